@@ -0,0 +1,269 @@
+//! Derive macros for [`serde-constant`](https://docs.rs/serde-constant).
+//!
+//! These are re-exported and documented by `serde-constant` with the `derive` feature, and their
+//! expansions refer to it, so depend on that instead of this crate.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Expr, Fields, Ident,
+    Result,
+};
+
+#[proc_macro_derive(ConstTagged, attributes(const_tag, serde))]
+pub fn derive_const_tagged(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// A variant of the enum, with its tag.
+struct Variant<'a> {
+    ident: &'a Ident,
+    tag: Expr,
+    serde_attrs: Vec<&'a Attribute>,
+    fields: Vec<Field<'a>>,
+    is_unit: bool,
+}
+
+/// A named field of a variant.
+struct Field<'a> {
+    ident: &'a Ident,
+    ty: &'a syn::Type,
+    serde_attrs: Vec<&'a Attribute>,
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`ConstTagged` can only be derived for enums",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`ConstTagged` can't be derived for generic enums",
+        ));
+    }
+
+    // report every bad variant at once
+    let mut variants = Vec::new();
+    let mut error = None::<Error>;
+    for variant in &data.variants {
+        match (parse_variant(variant), &mut error) {
+            (Ok(variant), _) => variants.push(variant),
+            (Err(err), Some(error)) => error.combine(err),
+            (Err(err), None) => error = Some(err),
+        }
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    // untagged enums take the first variant that fits, so a repeated tag would shadow a variant
+    let tag_consts = (0..variants.len())
+        .map(|i| Ident::new(&format!("__TAG_{i}"), Span::call_site()))
+        .collect::<Vec<_>>();
+    let tag_exprs = variants.iter().map(|v| &v.tag);
+    let mut distinct = Vec::new();
+    for (i, a) in variants.iter().enumerate() {
+        for (j, b) in variants.iter().enumerate().skip(i + 1) {
+            let (a_tag, b_tag) = (&tag_consts[i], &tag_consts[j]);
+            let message = format!(
+                "variants `{}` and `{}` have the same `#[const_tag(...)]`",
+                a.ident, b.ident,
+            );
+            distinct.push(quote_spanned! {b.tag.span()=>
+                ::core::assert!(#a_tag != #b_tag, #message);
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let enum_attrs = serde_attrs(&input.attrs);
+    // the shadow enums have the same name as the real one, so that error messages do too
+    let has_fields = variants.iter().any(|v| !v.fields.is_empty());
+    let ser_lifetime = has_fields.then(|| quote!(<'__a>));
+
+    let ser_variants = variants.iter().map(|v| {
+        let Variant {
+            ident,
+            tag,
+            serde_attrs,
+            ..
+        } = v;
+        let fields = v.fields.iter().map(|f| {
+            let Field {
+                ident,
+                ty,
+                serde_attrs,
+            } = f;
+            quote!(#(#serde_attrs)* #ident: &'__a #ty)
+        });
+        quote! {
+            #(#serde_attrs)*
+            #ident { tag: ::serde_constant::ConstI64<{ #tag }>, #(#fields,)* }
+        }
+    });
+    let de_variants = variants.iter().map(|v| {
+        let Variant {
+            ident,
+            tag,
+            serde_attrs,
+            ..
+        } = v;
+        let fields = v.fields.iter().map(|f| {
+            let Field {
+                ident,
+                ty,
+                serde_attrs,
+            } = f;
+            quote!(#(#serde_attrs)* #ident: #ty)
+        });
+        quote! {
+            #(#serde_attrs)*
+            #ident { tag: ::serde_constant::ConstI64<{ #tag }>, #(#fields,)* }
+        }
+    });
+    let ser_arms = variants.iter().map(|v| {
+        let ident = v.ident;
+        let fields = v.fields.iter().map(|f| f.ident).collect::<Vec<_>>();
+        let pattern = if v.is_unit {
+            quote!(Self::#ident)
+        } else {
+            quote!(Self::#ident { #(#fields),* })
+        };
+        quote! {
+            #pattern => #name::#ident { tag: ::serde_constant::ConstI64, #(#fields,)* }
+        }
+    });
+    let de_arms = variants.iter().map(|v| {
+        let ident = v.ident;
+        let fields = v.fields.iter().map(|f| f.ident).collect::<Vec<_>>();
+        let value = if v.is_unit {
+            quote!(Self::#ident)
+        } else {
+            quote!(Self::#ident { #(#fields),* })
+        };
+        quote! {
+            #name::#ident { tag: _, #(#fields,)* } => #value
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            #(const #tag_consts: i64 = #tag_exprs;)*
+            #(#distinct)*
+        };
+
+        #[automatically_derived]
+        impl ::serde_constant::__private::serde::Serialize for #name {
+            fn serialize<__S>(&self, __serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: ::serde_constant::__private::serde::Serializer,
+            {
+                #[derive(::serde_constant::__private::serde::Serialize)]
+                #[serde(crate = "::serde_constant::__private::serde", untagged)]
+                #(#enum_attrs)*
+                enum #name #ser_lifetime {
+                    #(#ser_variants,)*
+                }
+
+                let value = match self {
+                    #(#ser_arms,)*
+                };
+                ::serde_constant::__private::serde::Serialize::serialize(&value, __serializer)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de> ::serde_constant::__private::serde::Deserialize<'de> for #name {
+            fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: ::serde_constant::__private::serde::Deserializer<'de>,
+            {
+                #[derive(::serde_constant::__private::serde::Deserialize)]
+                #[serde(crate = "::serde_constant::__private::serde", untagged)]
+                #(#enum_attrs)*
+                enum #name {
+                    #(#de_variants,)*
+                }
+
+                let value = <#name as ::serde_constant::__private::serde::Deserialize>::deserialize(
+                    __deserializer,
+                )?;
+                ::core::result::Result::Ok(match value {
+                    #(#de_arms,)*
+                })
+            }
+        }
+    })
+}
+
+fn parse_variant(variant: &syn::Variant) -> Result<Variant<'_>> {
+    let mut tags = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("const_tag"));
+    let tag = match (tags.next(), tags.next()) {
+        (Some(tag), None) => tag.parse_args::<Expr>()?,
+        (None, _) => {
+            return Err(Error::new(
+                variant.ident.span(),
+                "missing `#[const_tag(...)]` on this variant",
+            ))
+        }
+        (Some(_), Some(extra)) => {
+            return Err(Error::new_spanned(
+                extra,
+                "a variant can only have one `#[const_tag(...)]`",
+            ))
+        }
+    };
+    let fields = match &variant.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let ident = field.ident.as_ref().unwrap();
+                if ident == "tag" {
+                    return Err(Error::new(
+                        ident.span(),
+                        "`tag` is the name of the generated tag field",
+                    ));
+                }
+                Ok(Field {
+                    ident,
+                    ty: &field.ty,
+                    serde_attrs: serde_attrs(&field.attrs),
+                })
+            })
+            .collect::<Result<_>>()?,
+        Fields::Unit => Vec::new(),
+        Fields::Unnamed(fields) => {
+            return Err(Error::new_spanned(
+                fields,
+                "`ConstTagged` needs struct or unit variants, since the tag is a named field",
+            ))
+        }
+    };
+    Ok(Variant {
+        ident: &variant.ident,
+        tag,
+        serde_attrs: serde_attrs(&variant.attrs),
+        fields,
+        is_unit: matches!(variant.fields, Fields::Unit),
+    })
+}
+
+fn serde_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .collect()
+}
@@ -0,0 +1,85 @@
+//! [`Arbitrary`] implementations, enabled by the `arbitrary` feature.
+//!
+//! Each constant has exactly one value, so generating one consumes no input.
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use serde_constant::{ConstBool, ConstI64};
+//!
+//! #[derive(Arbitrary, Debug)]
+//! struct Message {
+//!     version: ConstI64<2>,
+//!     compressed: ConstBool<false>,
+//!     payload: Vec<u8>,
+//! }
+//!
+//! let mut u = Unstructured::new(&[1, 2, 3, 4]);
+//! let message = Message::arbitrary(&mut u).unwrap();
+//! assert_eq!(message.version, 2);
+//! assert_eq!(<ConstI64<2> as Arbitrary>::size_hint(0), (0, Some(0)));
+//! ```
+use crate::*;
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+macro_rules! impl_arbitrary {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<'a, const V: $type> Arbitrary<'a> for $struct<V> {
+                fn arbitrary(_: &mut Unstructured<'a>) -> Result<Self> {
+                    Ok($struct::<V>)
+                }
+                fn size_hint(_: usize) -> (usize, Option<usize>) {
+                    (0, Some(0))
+                }
+            }
+        )*
+    };
+}
+
+with_const_types!(impl_arbitrary);
+
+impl<'a> Arbitrary<'a> for ConstUnit {
+    fn arbitrary(_: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ConstUnit)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ConstNanF64 {
+    fn arbitrary(_: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ConstNanF64)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ConstEmptyStr {
+    fn arbitrary(_: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ConstEmptyStr)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ConstEmptySeq {
+    fn arbitrary(_: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ConstEmptySeq)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, const V: i64, const NAME: &'static str> Arbitrary<'a> for ConstLabeled<V, NAME> {
+    fn arbitrary(_: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ConstLabeled::<V, NAME>)
+    }
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
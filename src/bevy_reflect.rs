@@ -0,0 +1,121 @@
+//! [`Reflect`](::bevy_reflect::Reflect) implementations, enabled by the `bevy_reflect` feature.
+//!
+//! Every constant is reflected as an opaque value, which compares, hashes, and serializes as the
+//! underlying value. This lets structs containing constants derive `Reflect`.
+//!
+//! ```
+//! use bevy_reflect::{prelude::*, Typed};
+//! use serde_constant::{ConstBool, ConstU32};
+//!
+//! #[derive(Reflect, Default)]
+//! struct Tile {
+//!     layer: ConstU32<3>,
+//!     solid: ConstBool<true>,
+//!     weight: f32,
+//! }
+//!
+//! let tile = Tile::default();
+//! assert_eq!(*tile.get_field::<ConstU32<3>>("layer").unwrap(), 3);
+//! let layer = tile.field("layer").unwrap();
+//! assert_eq!(layer.reflect_partial_eq(&ConstU32::<3>), Some(true));
+//! assert!(<ConstU32<3> as Typed>::type_info().as_opaque().is_ok());
+//! assert_eq!(ConstU32::<3>::type_path(), "serde_constant::ConstU32<3>");
+//! ```
+use crate::*;
+use ::bevy_reflect;
+use ::bevy_reflect::{
+    impl_reflect_opaque, std_traits::ReflectDefault, ReflectDeserialize, ReflectSerialize,
+};
+#[cfg(feature = "nightly")]
+use ::bevy_reflect::{utility::GenericTypePathCell, TypePath};
+#[cfg(feature = "nightly")]
+use alloc::format;
+
+macro_rules! impl_reflect {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl_reflect_opaque!((in serde_constant) $struct<const V: $type>(
+                Clone, Debug, PartialEq, Hash, Default, Serialize, Deserialize
+            ));
+        )*
+    };
+}
+
+impl_reflect!(
+    ConstBool<bool>,
+    ConstI8<i8>,
+    ConstI16<i16>,
+    ConstI32<i32>,
+    ConstI64<i64>,
+    ConstI128<i128>,
+    ConstIsize<isize>,
+    ConstU8<u8>,
+    ConstU16<u16>,
+    ConstU32<u32>,
+    ConstU64<u64>,
+    ConstU128<u128>,
+    ConstUsize<usize>,
+    ConstNonZeroI8<i8>,
+    ConstNonZeroI16<i16>,
+    ConstNonZeroI32<i32>,
+    ConstNonZeroI64<i64>,
+    ConstNonZeroI128<i128>,
+    ConstNonZeroIsize<isize>,
+    ConstNonZeroU8<u8>,
+    ConstNonZeroU16<u16>,
+    ConstNonZeroU32<u32>,
+    ConstNonZeroU64<u64>,
+    ConstNonZeroU128<u128>,
+    ConstNonZeroUsize<usize>,
+    ConstChar<char>,
+    ConstCharCi<char>,
+    ConstPrintableChar<char>,
+    ConstScalarU32<u32>,
+    ConstSafeU64<u64>,
+    ConstF32<u32>,
+    ConstF64<u64>,
+    ConstInfinityF64<bool>,
+);
+
+#[cfg(feature = "nightly")]
+impl_reflect!(ConstStr<&'static str>, ConstUnitVariant<&'static str>);
+
+// The generated type path needs `V: ToString`, which byte slices aren't
+#[cfg(feature = "nightly")]
+impl_reflect_opaque!((in serde_constant) ConstBytes<const V: &'static [u8]>(
+    Clone, Debug, PartialEq, Hash, Default, Serialize, Deserialize, type_path = false
+));
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static [u8]> TypePath for ConstBytes<V> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("serde_constant::ConstBytes<{}>", ConstBytes::<V>))
+    }
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("ConstBytes<{}>", ConstBytes::<V>))
+    }
+    fn type_ident() -> Option<&'static str> {
+        Some("ConstBytes")
+    }
+    fn crate_name() -> Option<&'static str> {
+        Some("serde_constant")
+    }
+    fn module_path() -> Option<&'static str> {
+        Some("serde_constant")
+    }
+}
+
+impl_reflect_opaque!((in serde_constant) ConstUnit(
+    Clone, Debug, PartialEq, Hash, Default, Serialize, Deserialize
+));
+impl_reflect_opaque!((in serde_constant) ConstNanF64(
+    Clone, Debug, PartialEq, Hash, Default, Serialize, Deserialize
+));
+impl_reflect_opaque!((in serde_constant) ConstEmptyStr(
+    Clone, Debug, PartialEq, Hash, Default, Serialize, Deserialize
+));
+impl_reflect_opaque!((in serde_constant) ConstEmptySeq(
+    Clone, Debug, PartialEq, Hash, Default, Serialize, Deserialize
+));
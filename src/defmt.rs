@@ -0,0 +1,72 @@
+//! [`Format`] implementations, enabled by the `defmt` feature.
+//!
+//! Each constant is logged as its underlying value, like its [`Display`](fmt::Display)
+//! implementation.
+//!
+//! ```
+//! use serde_constant::{ConstBool, ConstU8};
+//!
+//! #[derive(defmt::Format)]
+//! struct Header {
+//!     version: ConstU8<3>,
+//!     compressed: ConstBool<false>,
+//!     len: u32,
+//! }
+//!
+//! fn assert_format<T: defmt::Format>() {}
+//! assert_format::<Header>();
+//! ```
+use crate::*;
+use ::defmt::{Format, Formatter};
+
+macro_rules! impl_format {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> Format for $struct<V> {
+                fn format(&self, f: Formatter<'_>) {
+                    <$struct<V> as ConstValue>::VALUE.format(f)
+                }
+            }
+        )*
+    };
+}
+
+with_const_types!(impl_format);
+
+impl Format for ConstUnit {
+    fn format(&self, f: Formatter<'_>) {
+        ().format(f)
+    }
+}
+
+impl Format for ConstNanF64 {
+    fn format(&self, f: Formatter<'_>) {
+        f64::NAN.format(f)
+    }
+}
+
+impl Format for ConstEmptyStr {
+    fn format(&self, f: Formatter<'_>) {
+        "".format(f)
+    }
+}
+
+impl Format for ConstEmptySeq {
+    fn format(&self, f: Formatter<'_>) {
+        ConstEmptySeq::VALUE.format(f)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const VALUES: &'static [i64]> Format for ConstOneOfI64<VALUES> {
+    fn format(&self, f: Formatter<'_>) {
+        self.get().format(f)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> Format for ConstLabeled<V, NAME> {
+    fn format(&self, f: Formatter<'_>) {
+        V.format(f)
+    }
+}
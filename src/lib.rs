@@ -42,338 +42,8133 @@
 //! ));
 //! # Ok(()) }
 //! ```
+//!
+//! Self-describing binary formats work the same way. In CBOR, integers outside the 64-bit range
+//! are written as bignums, which `ciborium` reads back as 128-bit integers.
+//!
+//! ```
+//! use serde::{de::DeserializeOwned, Serialize};
+//! use serde_constant::*;
+//! fn roundtrip<T: Serialize + DeserializeOwned + Default + PartialEq + std::fmt::Debug>() {
+//!     let mut bytes = Vec::new();
+//!     ciborium::into_writer(&T::default(), &mut bytes).unwrap();
+//!     assert_eq!(ciborium::from_reader::<T, _>(&bytes[..]).unwrap(), T::default());
+//! }
+//!
+//! roundtrip::<ConstBool<true>>();
+//! roundtrip::<ConstI8<-5>>();
+//! roundtrip::<ConstI64<{ i64::MIN }>>();
+//! roundtrip::<ConstI128<{ i128::MIN }>>();
+//! roundtrip::<ConstU64<{ u64::MAX }>>();
+//! roundtrip::<ConstU128<{ u128::MAX }>>();
+//! roundtrip::<ConstUsize<7>>();
+//! roundtrip::<ConstNonZeroU128<{ u128::MAX }>>();
+//! roundtrip::<ConstChar<'🦀'>>();
+//! roundtrip::<ConstCharCi<'a'>>();
+//! roundtrip::<ConstUnit>();
+//! roundtrip::<const_f32!(1.5)>();
+//! roundtrip::<const_f64!(-0.25)>();
+//!
+//! let mut bytes = Vec::new();
+//! ciborium::into_writer(&ConstU128::<{ u128::MAX }>, &mut bytes).unwrap();
+//! assert!(ciborium::from_reader::<ConstU128<2>, _>(&bytes[..]).is_err());
+//! ```
+//!
+//! # `postcard`
+//!
+//! Every constant works with `postcard`, which suits `no_std` targets, and encodes as its
+//! underlying value would, except that [`ConstChar`] and the other char constants that aren't
+//! case-insensitive encode as their codepoint, a varint `u32`, rather than as a string the way
+//! `postcard` encodes a `char`. [`DynConstChar`] encodes the same way, so it can read them. The
+//! 128-bit integers are varints like the rest.
+//!
+//! `postcard` isn't self-describing, so [`ConstAny`] and the lenient features, which rely on
+//! `deserialize_any`, don't apply to it.
+//!
+//! ```
+//! use serde::{de::DeserializeOwned, Serialize};
+//! use serde_constant::*;
+//! fn roundtrip<T: Serialize + DeserializeOwned + Default + PartialEq + std::fmt::Debug>() {
+//!     let bytes = postcard::to_allocvec(&T::default()).unwrap();
+//!     assert_eq!(postcard::from_bytes::<T>(&bytes).unwrap(), T::default());
+//! }
+//! fn mismatch<T: DeserializeOwned + std::fmt::Debug>(wrong: impl Serialize) {
+//!     let bytes = postcard::to_allocvec(&wrong).unwrap();
+//!     postcard::from_bytes::<T>(&bytes).unwrap_err();
+//! }
+//!
+//! roundtrip::<ConstBool<true>>();
+//! mismatch::<ConstBool<true>>(false);
+//! roundtrip::<ConstI8<-5>>();
+//! mismatch::<ConstI8<-5>>(5i8);
+//! roundtrip::<ConstI16<-300>>();
+//! mismatch::<ConstI16<-300>>(300i16);
+//! roundtrip::<ConstI32<{ i32::MIN }>>();
+//! mismatch::<ConstI32<{ i32::MIN }>>(i32::MAX);
+//! roundtrip::<ConstI64<{ i64::MIN }>>();
+//! mismatch::<ConstI64<{ i64::MIN }>>(0i64);
+//! roundtrip::<ConstI128<{ i128::MIN }>>();
+//! mismatch::<ConstI128<{ i128::MIN }>>(i128::MAX);
+//! roundtrip::<ConstIsize<-7>>();
+//! mismatch::<ConstIsize<-7>>(7isize);
+//! roundtrip::<ConstU8<{ u8::MAX }>>();
+//! mismatch::<ConstU8<{ u8::MAX }>>(0u8);
+//! roundtrip::<ConstU16<300>>();
+//! mismatch::<ConstU16<300>>(301u16);
+//! roundtrip::<ConstU32<{ u32::MAX }>>();
+//! mismatch::<ConstU32<{ u32::MAX }>>(0u32);
+//! roundtrip::<ConstU64<{ u64::MAX }>>();
+//! mismatch::<ConstU64<{ u64::MAX }>>(u64::MAX - 1);
+//! roundtrip::<ConstU128<{ u128::MAX }>>();
+//! mismatch::<ConstU128<{ u128::MAX }>>(u128::MAX - 1);
+//! roundtrip::<ConstUsize<7>>();
+//! mismatch::<ConstUsize<7>>(8usize);
+//! roundtrip::<ConstNonZeroI128<{ i128::MIN }>>();
+//! mismatch::<ConstNonZeroI128<{ i128::MIN }>>(1i128);
+//! roundtrip::<ConstNonZeroU16<7>>();
+//! mismatch::<ConstNonZeroU16<7>>(8u16);
+//! roundtrip::<ConstChar<'🦀'>>();
+//! mismatch::<ConstChar<'🦀'>>(0x1F981u32);
+//! roundtrip::<ConstCharCi<'a'>>();
+//! mismatch::<ConstCharCi<'a'>>('b');
+//! roundtrip::<ConstPrintableChar<'a'>>();
+//! mismatch::<ConstPrintableChar<'a'>>(98u32);
+//! roundtrip::<ConstScalarU32<0x1F980>>();
+//! mismatch::<ConstScalarU32<0x1F980>>(0x1F981u32);
+//! roundtrip::<ConstCharRange<'a', 'z'>>();
+//! mismatch::<ConstCharRange<'a', 'z'>>('A' as u32);
+//! roundtrip::<ConstUnit>();
+//! roundtrip::<ConstEmptyStr>();
+//! mismatch::<ConstEmptyStr>("x");
+//! roundtrip::<ConstEmptySeq>();
+//! mismatch::<ConstEmptySeq>([1u8]);
+//! roundtrip::<ConstU64Str<5>>();
+//! mismatch::<ConstU64Str<5>>("6");
+//! roundtrip::<ConstI64Str<-5>>();
+//! mismatch::<ConstI64Str<-5>>("5");
+//! roundtrip::<const_f32!(1.5)>();
+//! mismatch::<const_f32!(1.5)>(2.5f32);
+//! roundtrip::<const_f64!(-0.25)>();
+//! mismatch::<const_f64!(-0.25)>(0.25f64);
+//! roundtrip::<ConstInfinityF64<true>>();
+//! mismatch::<ConstInfinityF64<true>>(f64::NEG_INFINITY);
+//! roundtrip::<ConstNanF64>();
+//! mismatch::<ConstNanF64>(0.0f64);
+//!
+//! // chars are encoded as their codepoint, not as a `char`
+//! assert_eq!(postcard::to_allocvec(&ConstChar::<'é'>).unwrap(), [0xE9, 0x01]);
+//! assert_eq!(postcard::to_allocvec(&'é').unwrap(), [2, 0xC3, 0xA9]);
+//! ```
+//!
+//! With the `nightly` feature, so do the types that hold strings, byte strings, and slices:
+//!
+//! ```
+//! # #[cfg(feature = "nightly")] {
+//! use serde::{de::DeserializeOwned, Serialize};
+//! use serde_constant::*;
+//! fn roundtrip<T: Serialize + DeserializeOwned + Default + PartialEq + std::fmt::Debug>() {
+//!     let bytes = postcard::to_allocvec(&T::default()).unwrap();
+//!     assert_eq!(postcard::from_bytes::<T>(&bytes).unwrap(), T::default());
+//! }
+//! fn mismatch<T: DeserializeOwned + std::fmt::Debug>(wrong: impl Serialize) {
+//!     let bytes = postcard::to_allocvec(&wrong).unwrap();
+//!     postcard::from_bytes::<T>(&bytes).unwrap_err();
+//! }
+//!
+//! roundtrip::<ConstStr<"hello">>();
+//! mismatch::<ConstStr<"hello">>("hellO");
+//! roundtrip::<ConstBytes<b"hello">>();
+//! mismatch::<ConstBytes<b"hello">>(b"hellO");
+//! roundtrip::<ConstUnitVariant<"Hello">>();
+//! roundtrip::<ConstOneOfI64<{ &[1, 2] }>>();
+//! mismatch::<ConstOneOfI64<{ &[1, 2] }>>(3i64);
+//! roundtrip::<Const<u16, 300>>();
+//! mismatch::<Const<u16, 300>>(301u16);
+//! roundtrip::<Const<char, 'é'>>();
+//! mismatch::<Const<char, 'é'>>('e');
+//! # }
+//! ```
+//!
+//! # Features
+//!
+//! - `nightly`: Enables `ConstStr`, `ConstUnitVariant`, `ConstBytes`, `ConstOneOfI64`,
+//!   `ConstLabeled`, and `Const`, which require the unstable `adt_const_params` and
+//!   `generic_const_parameter_types` features.
+//! - `alloc`: Reports rejected integers as a single "expected ..., found ..." message, e.g.
+//!   `expected the constant i64 2, found 3`, instead of serde's `invalid value` wording.
+//! - `lenient-strings`: Lets integer constants be deserialized from numeric strings, e.g. `"123"`,
+//!   and [`ConstBool`] from `"true"` and `"false"`.
+//! - `lenient-ints`: Lets [`ConstBool`] be deserialized from the integers `0` and `1`.
+//! - `accept-whole-floats`: Lets integer constants be deserialized from floats that are whole
+//!   numbers, e.g. `2.0`. Otherwise floats are rejected as the wrong type.
+//! - `bool-as-int`: Lets integer constants be deserialized from `true` and `false`, as `1` and `0`,
+//!   in human-readable formats.
+//! - `char-codepoints`: Lets [`ConstChar`] and [`DynConstChar`] be deserialized from their Unicode
+//!   codepoint, e.g. `65` for `'A'`, in human-readable formats. Compact formats always use the
+//!   codepoint.
+//! - `char-bytes`: Lets `ConstU8` be deserialized from a string of a single ASCII char, e.g. `"A"`
+//!   for `65`, in human-readable formats.
+//! - `yaml-bools`: Lets `ConstBool` be deserialized from the YAML 1.1 boolean tokens in any case,
+//!   e.g. `yes`, `on`, and `off`, in human-readable formats.
+//! - `trim-strings`: Lets [`ConstChar`] be deserialized from a string with ASCII whitespace around
+//!   the char, e.g. `" Y "`.
+//! - `serde-json-arbitrary-precision`: Enables `serde_json`'s `arbitrary_precision` feature, and
+//!   lets integer and float constants be deserialized from the maps it produces for numbers that
+//!   don't fit in an `i64` or `u64`, e.g. in untagged enums.
+//! - `derive`: Adds the `ConstTagged` derive macro, for enums whose variants are told apart by a
+//!   constant tag.
+//! - `arbitrary`: Implements `arbitrary::Arbitrary` for every constant, for use in fuzz targets.
+//! - `bevy_reflect`: Implements `bevy_reflect::Reflect` for every constant, as an opaque value.
+//! - `defmt`: Implements `defmt::Format` for every constant, which logs the underlying value.
+//! - `musli`: Implements `musli`'s `Encode` and `Decode` for every constant, which encode the
+//!   underlying value.
+//! - `proptest`: Implements `proptest::arbitrary::Arbitrary` for every constant, and adds
+//!   `const_strategy`.
+//! - `quickcheck`: Implements `quickcheck::Arbitrary` for every constant, which never shrinks.
+//! - `schemars`: Implements `schemars::JsonSchema` for every constant, using the `const` keyword.
+//! - `rkyv`: Implements `rkyv`'s `Archive`, `Serialize`, and `Deserialize` for every constant, which
+//!   archive as themselves and take up no space.
+//! - `scale-codec`: Implements `parity-scale-codec`'s `Encode`, `Decode`, and `MaxEncodedLen` for
+//!   the bool and fixed-width integer constants, encoding the underlying value.
+//! - `serde_with`: Implements `serde_with`'s `SerializeAs` and `DeserializeAs` for every constant,
+//!   so that fields of the underlying type can be checked with `#[serde_as(as = "ConstI64<2>")]`.
+//! - `test-util`: Adds the `testing` module, with `serde_test`-based assertions for constants.
+//! - `utoipa`: Implements `utoipa::ToSchema` for every constant, using a single-element `enum`.
+//! - `validator`: Implements `validator::Validate` for every constant, which is always valid, and
+//!   adds `ConstField`, which holds a value that is checked against a constant when it's validated.
+//! - `zeroize`: Implements `zeroize`'s `Zeroize` and `ZeroizeOnDrop` for every constant, as no-ops.
+//!
+//! The lenient features, `bool-as-int`, `char-codepoints`, `char-bytes`, `yaml-bools`, and
+//! `serde-json-arbitrary-precision` drive human-readable formats with `deserialize_any`, so that
+//! values of other types can reach the constant. Other formats are unaffected.
 #![no_std]
+#![cfg_attr(
+    feature = "nightly",
+    feature(adt_const_params, unsized_const_params, generic_const_parameter_types)
+)]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
 #![allow(clippy::unnecessary_cast)]
 #![warn(missing_docs)]
-use core::fmt;
+#[cfg(any(
+    feature = "alloc",
+    feature = "schemars",
+    feature = "test-util",
+    feature = "utoipa",
+    feature = "validator",
+    all(feature = "bevy_reflect", feature = "nightly")
+))]
+extern crate alloc;
+
+#[cfg(feature = "nightly")]
+use core::marker::{ConstParamTy_, PhantomData};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    str::FromStr,
+};
 use serde::{
-    de::{self, Unexpected, Visitor},
+    de::{self, Expected, IntoDeserializer, Unexpected, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-/// A const `bool`.
+/// Invokes `$m!` with every constant type that takes a const parameter, as `Struct<Type>`.
+#[allow(unused_macros)]
+macro_rules! with_const_types {
+    ($m:ident) => {
+        $m!(
+            ConstBool<bool>,
+            ConstI8<i8>,
+            ConstI16<i16>,
+            ConstI32<i32>,
+            ConstI64<i64>,
+            ConstI128<i128>,
+            ConstIsize<isize>,
+            ConstU8<u8>,
+            ConstU16<u16>,
+            ConstU32<u32>,
+            ConstU64<u64>,
+            ConstU128<u128>,
+            ConstUsize<usize>,
+            ConstNonZeroI8<i8>,
+            ConstNonZeroI16<i16>,
+            ConstNonZeroI32<i32>,
+            ConstNonZeroI64<i64>,
+            ConstNonZeroI128<i128>,
+            ConstNonZeroIsize<isize>,
+            ConstNonZeroU8<u8>,
+            ConstNonZeroU16<u16>,
+            ConstNonZeroU32<u32>,
+            ConstNonZeroU64<u64>,
+            ConstNonZeroU128<u128>,
+            ConstNonZeroUsize<usize>,
+            ConstChar<char>,
+            ConstCharCi<char>,
+            ConstPrintableChar<char>,
+            ConstScalarU32<u32>,
+            ConstSafeU64<u64>,
+            ConstF32<u32>,
+            ConstF64<u64>,
+            ConstInfinityF64<bool>,
+        );
+        #[cfg(feature = "nightly")]
+        $m!(
+            ConstStr<&'static str>,
+            ConstUnitVariant<&'static str>,
+            ConstBytes<&'static [u8]>,
+        );
+    };
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bevy_reflect")]
+mod bevy_reflect;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "musli")]
+mod musli;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "scale-codec")]
+mod scale_codec;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde_with")]
+mod serde_with;
+#[cfg(feature = "test-util")]
+pub mod testing;
+#[cfg(feature = "utoipa")]
+mod utoipa;
+#[cfg(feature = "validator")]
+mod validator;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "proptest")]
+pub use crate::proptest::const_strategy;
+#[cfg(feature = "validator")]
+pub use crate::validator::ConstField;
+
+/// Derives `Serialize` and `Deserialize` for an enum whose variants are told apart by a
+/// [`ConstI64`] tag. Requires the `derive` feature.
+///
+/// Each variant is marked with `#[const_tag(N)]`, and (de)serializes as if it had a
+/// `tag: ConstI64<N>` field before its own fields, in an enum marked `#[serde(untagged)]`. So the
+/// tag decides which variant is deserialized, without the enum having to store it. Unit variants
+/// (de)serialize as variants with only a tag.
+///
+/// `#[serde]` attributes on the enum, its variants, and their fields are passed on. When
+/// serializing, the fields are references, so functions given to `serialize_with` or
+/// `skip_serializing_if` take a `&&T`. Tuple variants and generic enums aren't supported, no field
+/// can be named `tag`, and no two variants can have the same tag.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_constant::{ConstI64, ConstTagged};
+///
+/// #[derive(ConstTagged, Debug, PartialEq)]
+/// pub enum Message {
+///     #[const_tag(1)]
+///     Ping { id: u64 },
+///     /// A reply to a ping.
+///     #[const_tag(2)]
+///     Pong { id: u64, latency: u32 },
+///     #[const_tag(3)]
+///     Close,
+/// }
+///
+/// // what it's equivalent to
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// #[serde(untagged)]
+/// pub enum ByHand {
+///     Ping { tag: ConstI64<1>, id: u64 },
+///     Pong { tag: ConstI64<2>, id: u64, latency: u32 },
+///     Close { tag: ConstI64<3> },
+/// }
+///
+/// let pong = Message::Pong { id: 7, latency: 30 };
+/// let by_hand = ByHand::Pong { tag: ConstI64, id: 7, latency: 30 };
+/// assert_eq!(serde_json::to_value(&pong).unwrap(), serde_json::to_value(&by_hand).unwrap());
+/// assert_eq!(
+///     serde_json::to_value(&pong).unwrap(),
+///     json!({ "tag": 2, "id": 7, "latency": 30 }),
+/// );
+///
+/// // the tag picks the variant, even though `Ping`'s fields are a subset of `Pong`'s
+/// assert_eq!(
+///     serde_json::from_value::<Message>(json!({ "tag": 2, "id": 7, "latency": 30 })).unwrap(),
+///     pong,
+/// );
+/// assert_eq!(
+///     serde_json::from_value::<Message>(json!({ "tag": 1, "id": 7, "latency": 30 })).unwrap(),
+///     Message::Ping { id: 7 },
+/// );
+/// assert_eq!(serde_json::from_value::<Message>(json!({ "tag": 3 })).unwrap(), Message::Close);
+/// assert_eq!(
+///     serde_json::from_value::<Message>(json!({ "tag": 4 })).unwrap_err().to_string(),
+///     "data did not match any variant of untagged enum Message",
+/// );
+/// ```
+#[cfg(feature = "derive")]
+pub use serde_constant_derive::ConstTagged;
+
+/// A type with exactly one value, which is known at compile time.
+///
+/// This is implemented by every constant type in this crate, so generic code can recover the
+/// underlying value.
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::{ConstI64, ConstValue};
+/// fn tag<T: ConstValue<Repr = i64>>() -> i64 {
+///     T::VALUE
+/// }
+///
+/// assert_eq!(ConstI64::<2>::VALUE, 2);
+/// assert_eq!(tag::<ConstI64<2>>(), 2);
+/// assert_eq!(ConstI64::<2>.value(), 2);
+/// ```
+///
+/// The constants hold no data, so there's nothing for [`AsRef`] or [`Borrow`](core::borrow::Borrow)
+/// to point at. Instead, [`value`](ConstValue::value) returns a copy from a reference, and each
+/// constant also converts to its underlying value from a reference:
+///
+/// ```
+/// use serde_constant::{ConstBool, ConstI64, ConstValue};
+/// fn read<T: ConstValue>(constant: &T) -> T::Repr {
+///     constant.value()
+/// }
+/// fn sum<'a, T: 'a>(items: impl IntoIterator<Item = &'a T>) -> i64
+/// where
+///     &'a T: Into<i64>,
+/// {
+///     items.into_iter().map(Into::into).sum()
+/// }
+///
+/// let tag = ConstI64::<2>;
+/// assert_eq!(read(&tag), 2);
+/// assert_eq!(i64::from(&tag), 2);
+/// assert_eq!(sum(&[tag, tag, tag]), 6);
+/// assert!(bool::from(&ConstBool::<true>));
+/// ```
+///
+/// Generic code can construct a constant with [`new`](ConstValue::new). Each constant also has an
+/// inherent `const fn new` and a `NEW` constant, for `const` items and struct literals:
+///
+/// ```
+/// # use serde::Serialize;
+/// use serde_constant::{ConstBool, ConstI64, ConstValue};
+/// #[derive(Serialize)]
+/// struct Envelope<T, P> {
+///     tag: T,
+///     payload: P,
+/// }
+///
+/// fn wrap<T: ConstValue, P>(payload: P) -> Envelope<T, P> {
+///     Envelope { tag: T::new(), payload }
+/// }
+///
+/// let envelope = wrap::<ConstI64<2>, _>("hi");
+/// assert_eq!(envelope.tag, 2);
+/// assert_eq!(serde_json::to_string(&envelope).unwrap(), r#"{"tag":2,"payload":"hi"}"#);
+///
+/// const ENABLED: ConstBool<true> = ConstBool::NEW;
+/// let envelope = Envelope { tag: ConstI64::<3>::new(), payload: ENABLED };
+/// assert_eq!(serde_json::to_string(&envelope).unwrap(), r#"{"tag":3,"payload":true}"#);
+/// ```
+pub trait ConstValue: Default {
+    /// The type of the underlying value.
+    type Repr;
+    /// The underlying value.
+    const VALUE: Self::Repr;
+    /// Returns the underlying value.
+    fn value(&self) -> Self::Repr {
+        Self::VALUE
+    }
+    /// Returns the constant, which is its [`Default`] value.
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Hashes the underlying value, rather than nothing, so that constants hash like their values.
+macro_rules! impl_hash {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> Hash for $struct<V> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    V.hash(state)
+                }
+            }
+        )*
+    };
+}
+
+with_const_types!(impl_hash);
+
+/// Compares two constants by their underlying values.
+///
+/// Constants with different values are different types, so their derived [`Ord`] implementations
+/// can't compare them to each other. This can.
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use serde_constant::{const_cmp, ConstI64};
+/// assert_eq!(const_cmp(ConstI64::<1>, ConstI64::<2>), Ordering::Less);
+/// assert_eq!(const_cmp(ConstI64::<2>, ConstI64::<1>), Ordering::Greater);
+/// assert_eq!(const_cmp(ConstI64::<2>, ConstI64::<2>), Ordering::Equal);
+/// ```
+pub fn const_cmp<A, B>(_: A, _: B) -> Ordering
+where
+    A: ConstValue,
+    B: ConstValue<Repr = A::Repr>,
+    A::Repr: Ord,
+{
+    A::VALUE.cmp(&B::VALUE)
+}
+
+/// Compares two `f64` constants by their underlying values, using the IEEE 754 total order.
+///
+/// `f64` isn't [`Ord`], so [`const_cmp`] can't compare float constants. This uses
+/// [`f64::total_cmp`] instead, which orders `-0.0` before `0.0`, and puts positive NaNs after
+/// every other value and negative NaNs before them.
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use serde_constant::{const_f64, const_f64_cmp, ConstInfinityF64, ConstNanF64};
+/// type NegZero = const_f64!(-0.0);
+/// type Zero = const_f64!(0.0);
+/// type One = const_f64!(1.0);
+///
+/// assert_eq!(const_f64_cmp(NegZero::default(), Zero::default()), Ordering::Less);
+/// assert_eq!(const_f64_cmp(Zero::default(), One::default()), Ordering::Less);
+/// assert_eq!(const_f64_cmp(One::default(), NegZero::default()), Ordering::Greater);
+/// assert_eq!(const_f64_cmp(Zero::default(), Zero::default()), Ordering::Equal);
+/// assert_eq!(const_f64_cmp(ConstInfinityF64::<true>, ConstNanF64), Ordering::Less);
+/// ```
+pub fn const_f64_cmp<A, B>(_: A, _: B) -> Ordering
+where
+    A: ConstValue<Repr = f64>,
+    B: ConstValue<Repr = f64>,
+{
+    A::VALUE.total_cmp(&B::VALUE)
+}
+
+/// The error returned when a value doesn't match a constant.
+///
+/// This is returned by the [`TryFrom`] implementations of the constant types, which provide a way to
+/// check a value against a constant without going through serde, and by [`deserialize_checked`].
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::{ConstBool, ConstChar, ConstI64, ConstMismatch};
+/// assert!(ConstBool::<true>::try_from(true).is_ok());
+/// assert!(ConstChar::<'x'>::try_from('y').is_err());
+/// assert_eq!(ConstI64::<2>::try_from(2), Ok(ConstI64::<2>));
+/// let err = ConstI64::<2>::try_from(3).unwrap_err();
+/// assert_eq!(err, ConstMismatch { expected: 2, found: 3 });
+/// assert_eq!(err.to_string(), "expected 2, found 3");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConstMismatch<T> {
+    /// The value of the constant.
+    pub expected: T,
+    /// The value that was checked against the constant.
+    pub found: T,
+}
+
+impl<T: fmt::Display> fmt::Display for ConstMismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {}, found {}", self.expected, self.found)
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> core::error::Error for ConstMismatch<T> {}
+
+/// The error returned when parsing a constant from a string fails.
+///
+/// This is returned by the [`FromStr`] implementations of the constant types, which parse the
+/// string as the underlying type and then check it against the constant.
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::{ConstBool, ConstChar, ConstI64, ConstMismatch, ParseConstError};
+/// assert_eq!("2".parse::<ConstI64<2>>(), Ok(ConstI64::<2>));
+/// assert_eq!(
+///     "3".parse::<ConstI64<2>>(),
+///     Err(ParseConstError::Mismatch(ConstMismatch { expected: 2, found: 3 })),
+/// );
+/// assert!(matches!("two".parse::<ConstI64<2>>(), Err(ParseConstError::Invalid(_))));
+/// assert_eq!("3".parse::<ConstI64<2>>().unwrap_err().to_string(), "expected 2, found 3");
+/// assert_eq!(
+///     "two".parse::<ConstI64<2>>().unwrap_err().to_string(),
+///     "invalid digit found in string",
+/// );
+///
+/// assert!("true".parse::<ConstBool<true>>().is_ok());
+/// assert!("false".parse::<ConstBool<true>>().is_err());
+/// assert!("yes".parse::<ConstBool<true>>().is_err());
+/// assert!("x".parse::<ConstChar<'x'>>().is_ok());
+/// assert!("y".parse::<ConstChar<'x'>>().is_err());
+/// assert!("xx".parse::<ConstChar<'x'>>().is_err());
+/// ```
+pub enum ParseConstError<T: FromStr> {
+    /// The string isn't a valid `T`.
+    Invalid(T::Err),
+    /// The string is a valid `T`, but not the constant.
+    Mismatch(ConstMismatch<T>),
+}
+
+impl<T: FromStr + fmt::Debug> fmt::Debug for ParseConstError<T>
+where
+    T::Err: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseConstError::Invalid(err) => f.debug_tuple("Invalid").field(err).finish(),
+            ParseConstError::Mismatch(err) => f.debug_tuple("Mismatch").field(err).finish(),
+        }
+    }
+}
+
+impl<T: FromStr + Clone> Clone for ParseConstError<T>
+where
+    T::Err: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            ParseConstError::Invalid(err) => ParseConstError::Invalid(err.clone()),
+            ParseConstError::Mismatch(err) => ParseConstError::Mismatch(err.clone()),
+        }
+    }
+}
+
+impl<T: FromStr + PartialEq> PartialEq for ParseConstError<T>
+where
+    T::Err: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ParseConstError::Invalid(a), ParseConstError::Invalid(b)) => a == b,
+            (ParseConstError::Mismatch(a), ParseConstError::Mismatch(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T: FromStr + fmt::Display> fmt::Display for ParseConstError<T>
+where
+    T::Err: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseConstError::Invalid(err) => fmt::Display::fmt(err, f),
+            ParseConstError::Mismatch(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl<T: FromStr + fmt::Debug + fmt::Display> core::error::Error for ParseConstError<T> where
+    T::Err: core::error::Error
+{
+}
+
+/// Parses `s` as a `T`, then checks it against the constant `C`.
+fn parse_const<T, C>(s: &str) -> Result<C, ParseConstError<T>>
+where
+    T: FromStr,
+    C: TryFrom<T, Error = ConstMismatch<T>>,
+{
+    let value = s.parse().map_err(ParseConstError::Invalid)?;
+    C::try_from(value).map_err(ParseConstError::Mismatch)
+}
+
+/// A constant that can be deserialized through [`Deserializer::deserialize_any`].
+///
+/// This is implemented by every constant type in this crate, for use with [`ConstAny`].
+pub trait DeserializeAny<'de>: Sized {
+    /// Deserializes the constant, letting the format decide which type of value to produce.
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// A wrapper that deserializes the constant `T` with [`Deserializer::deserialize_any`].
+///
+/// Normally constants pass their own type as a hint, e.g. [`ConstU8`] calls
+/// [`Deserializer::deserialize_u8`]. Self-describing formats can produce the value however it was
+/// written, and some don't handle hints for a different integer width gracefully. The wrapper
+/// serializes exactly like `T`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::de::{self, value, Deserialize, Deserializer, Visitor};
+/// use serde_constant::{ConstAny, ConstU8};
+///
+/// /// A deserializer that only understands its own type.
+/// struct OnlyU64(u64);
+///
+/// impl<'de> Deserializer<'de> for OnlyU64 {
+///     type Error = value::Error;
+///     fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+///         visitor.visit_u64(self.0)
+///     }
+///     fn deserialize_u8<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+///         Err(de::Error::custom("not a u8"))
+///     }
+///     fn is_human_readable(&self) -> bool {
+///         false
+///     }
+///     serde::forward_to_deserialize_any! {
+///         bool i8 i16 i32 i64 i128 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+///         option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum
+///         identifier ignored_any
+///     }
+/// }
+///
+/// assert!(ConstU8::<5>::deserialize(OnlyU64(5)).is_err());
+/// assert!(ConstAny::<ConstU8<5>>::deserialize(OnlyU64(5)).is_ok());
+/// assert!(ConstAny::<ConstU8<5>>::deserialize(OnlyU64(6)).is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default, Debug)]
+pub struct ConstAny<T>(pub T);
+
+impl<T: ConstValue> ConstValue for ConstAny<T> {
+    type Repr = T::Repr;
+    const VALUE: T::Repr = T::VALUE;
+}
+
+impl<T: fmt::Display> fmt::Display for ConstAny<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: Serialize> Serialize for ConstAny<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: DeserializeAny<'de>> Deserialize<'de> for ConstAny<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_any(deserializer).map(ConstAny)
+    }
+}
+
+/// A wrapper that serializes the constant `T` as a string, for use as a map key.
+///
+/// Some serializers only accept strings as map keys, and serde doesn't tell a constant when it's
+/// being serialized as one. The wrapper writes the underlying value with its [`Display`]
+/// implementation, e.g. `2` as `"2"` and `'x'` as `"x"`, and deserializes from a string with the
+/// constant's [`FromStr`] implementation.
+///
+/// [`Display`]: fmt::Display
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::BTreeMap;
+/// # use serde_json::json;
+/// use serde_constant::{ConstAsKey, ConstChar, ConstI64};
+/// let mut map = BTreeMap::new();
+/// map.insert(ConstAsKey(ConstI64::<2>), "two".to_string());
+/// assert_eq!(serde_json::to_value(&map).unwrap(), json!({ "2": "two" }));
+/// assert_eq!(
+///     serde_json::from_value::<BTreeMap<ConstAsKey<ConstI64<2>>, String>>(json!({ "2": "two" }))
+///         .unwrap(),
+///     map,
+/// );
+/// assert!(
+///     serde_json::from_value::<BTreeMap<ConstAsKey<ConstI64<2>>, String>>(json!({ "3": "three" }))
+///         .is_err()
+/// );
+///
+/// assert_eq!(serde_json::to_value(ConstAsKey(ConstChar::<'x'>)).unwrap(), json!("x"));
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default, Debug)]
+pub struct ConstAsKey<T>(pub T);
+
+impl<T: ConstValue> ConstValue for ConstAsKey<T> {
+    type Repr = T::Repr;
+    const VALUE: T::Repr = T::VALUE;
+}
+
+impl<T: fmt::Display> fmt::Display for ConstAsKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<T> Serialize for ConstAsKey<T>
+where
+    T: ConstValue,
+    T::Repr: fmt::Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&T::VALUE)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for ConstAsKey<T>
+where
+    T: ConstValue + FromStr,
+    T::Repr: fmt::Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ConstAsKeyVisitor(core::marker::PhantomData))
+    }
+}
+
+struct ConstAsKeyVisitor<T>(core::marker::PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for ConstAsKeyVisitor<T>
+where
+    T: ConstValue + FromStr,
+    T::Repr: fmt::Display,
+{
+    type Value = ConstAsKey<T>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant string \"{}\"", T::VALUE)
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match v.parse() {
+            Ok(constant) => Ok(ConstAsKey(constant)),
+            Err(_) => Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+/// A wrapper for the constant `T` in a `#[serde(flatten)]` field, which serializes as nothing but
+/// still has to be present on input, under the key named by the string constant `K`.
+///
+/// This trims a redundant tag from the output of a struct whose readers already know it. `K` is a
+/// string constant, such as one declared with [`const_string!`] or, with the `nightly` feature, a
+/// `ConstStr`. The wrapper serializes as an empty map, which adds no entries to the flattening
+/// struct, and deserializes from a map by finding `K` among the remaining entries and checking its
+/// value against `T`.
+///
+/// Outside of `flatten`, a plain constant with `#[serde(skip_serializing)]` does the same job.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_constant::{const_string, ConstI64, ConstSkipSer};
+/// const_string!(Version = "version");
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Reading {
+///     #[serde(flatten)]
+///     version: ConstSkipSer<Version, ConstI64<2>>,
+///     celsius: f64,
+/// }
+///
+/// let reading = Reading { version: ConstSkipSer::new(), celsius: 21.5 };
+/// assert_eq!(serde_json::to_value(&reading).unwrap(), json!({ "celsius": 21.5 }));
+///
+/// let input = json!({ "version": 2, "celsius": 21.5 });
+/// assert_eq!(serde_json::from_value::<Reading>(input).unwrap(), reading);
+/// assert_eq!(
+///     serde_json::from_value::<Reading>(json!({ "celsius": 21.5 })).unwrap_err().to_string(),
+///     "missing field `version`",
+/// );
+/// assert!(serde_json::from_value::<Reading>(json!({ "version": 3, "celsius": 21.5 })).is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+pub struct ConstSkipSer<K, T>(core::marker::PhantomData<fn() -> (K, T)>);
+
+impl<K, T> ConstSkipSer<K, T> {
+    /// Returns the wrapper.
+    pub const fn new() -> Self {
+        ConstSkipSer(core::marker::PhantomData)
+    }
+}
+
+impl<K: Default, T: ConstValue> ConstValue for ConstSkipSer<K, T> {
+    type Repr = T::Repr;
+    const VALUE: T::Repr = T::VALUE;
+}
+
+impl<K, T> fmt::Debug for ConstSkipSer<K, T>
+where
+    K: ConstValue<Repr = &'static str>,
+    T: fmt::Debug + Default,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConstSkipSer")
+            .field("key", &K::VALUE)
+            .field("value", &T::default())
+            .finish()
+    }
+}
+
+impl<K, T> Serialize for ConstSkipSer<K, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serde::ser::SerializeMap::end(serializer.serialize_map(Some(0))?)
+    }
+}
+
+impl<'de, K, T> Deserialize<'de> for ConstSkipSer<K, T>
+where
+    K: ConstValue<Repr = &'static str>,
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ConstSkipSerVisitor(core::marker::PhantomData))
+    }
+}
+
+struct ConstSkipSerVisitor<K, T>(core::marker::PhantomData<fn() -> (K, T)>);
+
+impl<'de, K, T> Visitor<'de> for ConstSkipSerVisitor<K, T>
+where
+    K: ConstValue<Repr = &'static str>,
+    T: Deserialize<'de>,
+{
+    type Value = ConstSkipSer<K, T>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map with the field `{}`", K::VALUE)
+    }
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut found = false;
+        while let Some(is_key) = map.next_key_seed(KeyMatches(K::VALUE))? {
+            if !is_key {
+                map.next_value::<de::IgnoredAny>()?;
+            } else if found {
+                return Err(de::Error::duplicate_field(K::VALUE));
+            } else {
+                map.next_value::<T>()?;
+                found = true;
+            }
+        }
+        if found {
+            Ok(ConstSkipSer::new())
+        } else {
+            Err(de::Error::missing_field(K::VALUE))
+        }
+    }
+}
+
+/// Deserializes a map key as whether it is the given field name.
+struct KeyMatches(&'static str);
+
+impl<'de> de::DeserializeSeed<'de> for KeyMatches {
+    type Value = bool;
+    fn deserialize<D>(self, deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeyMatches {
+    type Value = bool;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a field name")
+    }
+    fn visit_str<E>(self, v: &str) -> Result<bool, E>
+    where
+        E: de::Error,
+    {
+        Ok(v == self.0)
+    }
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<bool, E>
+    where
+        E: de::Error,
+    {
+        Ok(v == self.0.as_bytes())
+    }
+    fn visit_u64<E>(self, _: u64) -> Result<bool, E>
+    where
+        E: de::Error,
+    {
+        Ok(false)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Any of the constant types in this crate.
+///
+/// This lets generic code deserialize a constant without naming its type, e.g. with
+/// [`skip_const`]. It is sealed, so it can't be implemented outside this crate, and types made with
+/// the declaration macros, such as [`declare_const!`] and [`const_string!`], don't implement it.
+pub trait AnyConst: for<'de> Deserialize<'de> + Serialize + Default + sealed::Sealed {}
+
+/// Deserializes the constant `T` and discards it, failing if the value isn't the constant.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::{skip_const, AnyConst, ConstBool, ConstI64};
+/// fn check<T: AnyConst>(value: serde_json::Value) -> bool {
+///     skip_const::<T, _>(value).is_ok()
+/// }
+///
+/// assert!(check::<ConstI64<2>>(json!(2)));
+/// assert!(!check::<ConstI64<2>>(json!(3)));
+/// assert!(check::<ConstBool<true>>(json!(true)));
+/// assert!(!check::<ConstBool<true>>(json!(2)));
+/// ```
+pub fn skip_const<'de, T: AnyConst, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+    T::deserialize(deserializer).map(|_| ())
+}
+
+/// Deserializes the underlying value of the constant `C`, then checks it against the constant.
+///
+/// A value that doesn't match isn't a deserialization error. Instead, the [`ConstMismatch`] is
+/// returned alongside it, so that the value that was found can be inspected or recovered from. The
+/// value is deserialized as [`ConstValue::Repr`], so the lenient features don't apply.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::{deserialize_checked, ConstI64, ConstMismatch};
+/// let checked = deserialize_checked::<ConstI64<2>, _>(json!(2)).unwrap();
+/// assert_eq!(checked, Ok(ConstI64::<2>));
+///
+/// let checked = deserialize_checked::<ConstI64<2>, _>(json!(3)).unwrap();
+/// assert_eq!(checked, Err(ConstMismatch { expected: 2, found: 3 }));
+/// let found = checked.unwrap_err().found;
+/// assert_eq!(found, 3);
+///
+/// // values of the wrong type are still deserialization errors
+/// assert!(deserialize_checked::<ConstI64<2>, _>(json!("two")).is_err());
+/// ```
+pub fn deserialize_checked<'de, C, D>(
+    deserializer: D,
+) -> Result<Result<C, ConstMismatch<C::Repr>>, D::Error>
+where
+    C: ConstValue + TryFrom<C::Repr, Error = ConstMismatch<C::Repr>>,
+    C::Repr: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    C::Repr::deserialize(deserializer).map(C::try_from)
+}
+
+macro_rules! impl_any_const {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> sealed::Sealed for $struct<V> {}
+
+            impl<const V: $type> AnyConst for $struct<V> {}
+        )*
+    };
+}
+
+with_const_types!(impl_any_const);
+impl_any_const!(ConstU64Str<u64>, ConstI64Str<i64>);
+#[cfg(feature = "nightly")]
+impl_any_const!(ConstOneOfI64<&'static [i64]>);
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> sealed::Sealed for ConstLabeled<V, NAME> {}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> AnyConst for ConstLabeled<V, NAME> {}
+
+impl sealed::Sealed for ConstUnit {}
+
+impl AnyConst for ConstUnit {}
+
+impl sealed::Sealed for ConstEmptyStr {}
+
+impl AnyConst for ConstEmptyStr {}
+
+impl sealed::Sealed for ConstEmptySeq {}
+
+impl AnyConst for ConstEmptySeq {}
+
+impl sealed::Sealed for ConstNanF64 {}
+
+impl AnyConst for ConstNanF64 {}
+
+impl<const LO: char, const HI: char> sealed::Sealed for ConstCharRange<LO, HI> {}
+
+impl<const LO: char, const HI: char> AnyConst for ConstCharRange<LO, HI> {}
+
+/// A const `bool`.
+///
+/// Deserialization fails if the value is not `V`.
+///
+/// With the `lenient-strings` feature, the strings `"true"` and `"false"` are accepted as well.
+///
+/// ```
+/// # #[cfg(feature = "lenient-strings")] {
+/// # use serde_json::json;
+/// use serde_constant::ConstBool;
+/// assert!(serde_json::from_value::<ConstBool<true>>(json!("true")).is_ok());
+/// assert!(serde_json::from_value::<ConstBool<true>>(json!("false")).is_err());
+/// assert!(serde_json::from_value::<ConstBool<true>>(json!("maybe")).is_err());
+/// # }
+/// ```
+///
+/// With the `lenient-ints` feature, `0` and `1` are accepted as `false` and `true`.
+///
+/// ```
+/// # #[cfg(feature = "lenient-ints")] {
+/// # use serde_json::json;
+/// use serde_constant::ConstBool;
+/// assert!(serde_json::from_value::<ConstBool<true>>(json!(1)).is_ok());
+/// assert!(serde_json::from_value::<ConstBool<true>>(json!(0)).is_err());
+/// assert!(serde_json::from_value::<ConstBool<true>>(json!(2)).is_err());
+/// # }
+/// ```
+///
+/// With the `yaml-bools` feature, the YAML 1.1 boolean tokens are accepted in any case: `y`,
+/// `yes`, `true`, and `on` as `true`, and `n`, `no`, `false`, and `off` as `false`.
+///
+/// ```
+/// # #[cfg(feature = "yaml-bools")] {
+/// # use serde::Deserialize;
+/// use serde_constant::ConstBool;
+/// for token in ["y", "Y", "yes", "Yes", "YES", "true", "True", "TRUE", "on", "On", "ON", "yEs"] {
+///     assert!(serde_yaml::from_str::<ConstBool<true>>(token).is_ok(), "{token}");
+///     assert!(serde_yaml::from_str::<ConstBool<false>>(token).is_err(), "{token}");
+/// }
+/// for token in ["n", "N", "no", "No", "NO", "false", "False", "FALSE", "off", "Off", "OFF", "oFf"] {
+///     assert!(serde_yaml::from_str::<ConstBool<false>>(token).is_ok(), "{token}");
+///     assert!(serde_yaml::from_str::<ConstBool<true>>(token).is_err(), "{token}");
+/// }
+/// assert_eq!(
+///     serde_yaml::from_str::<ConstBool<true>>("enabled").unwrap_err().to_string(),
+///     "invalid value: string \"enabled\", expected the constant bool true",
+/// );
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[allow(dead_code)]
+///     verbose: ConstBool<true>,
+/// }
+/// assert!(serde_yaml::from_str::<Config>("verbose: yes").is_ok());
+/// assert!(serde_yaml::from_str::<Config>("verbose: off").is_err());
+/// # }
+/// ```
+///
+/// Inside the variants of an internally tagged enum, serde buffers each field and replays it with
+/// its original type, so a `true` in the input still reaches `ConstBool` as a bool. The tag itself
+/// has to be a string, so a bool discriminant has to live in an ordinary field, with the enum
+/// untagged instead. Formats that can only produce strings, like query strings, need the
+/// `lenient-strings` feature.
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// use serde_constant::ConstBool;
+/// #[derive(Deserialize, Debug)]
+/// #[serde(tag = "type")]
+/// enum Event {
+///     Toggle { on: ConstBool<true> },
+///     Reset,
+/// }
+///
+/// assert!(matches!(
+///     serde_json::from_str(r#"{ "on": true, "type": "Toggle" }"#),
+///     Ok(Event::Toggle { .. }),
+/// ));
+/// assert_eq!(
+///     serde_json::from_value::<Event>(json!({ "type": "Toggle", "on": false }))
+///         .unwrap_err()
+///         .to_string(),
+///     "invalid value: boolean `false`, expected the constant bool true",
+/// );
+/// # #[cfg(feature = "lenient-strings")]
+/// assert!(serde_json::from_value::<Event>(json!({ "type": "Toggle", "on": "true" })).is_ok());
+///
+/// #[derive(Deserialize, Debug)]
+/// #[serde(untagged)]
+/// enum Switch {
+///     On { enabled: ConstBool<true>, level: u8 },
+///     Off { enabled: ConstBool<false> },
+/// }
+///
+/// assert!(matches!(
+///     serde_json::from_value(json!({ "enabled": false, "level": 3 })),
+///     Ok(Switch::Off { .. }),
+/// ));
+/// ```
+///
+/// `#[serde(flatten)]` buffers fields the same way, so flattened constants are still checked.
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_constant::ConstBool;
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Header {
+///     signed: ConstBool<true>,
+/// }
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Message {
+///     body: String,
+///     #[serde(flatten)]
+///     header: Header,
+/// }
+///
+/// let json = r#"{"body":"hi","signed":true}"#;
+/// let message: Message = serde_json::from_str(json).unwrap();
+/// assert_eq!(message.body, "hi");
+/// assert_eq!(serde_json::to_string(&message).unwrap(), json);
+///
+/// let unsigned = json!({ "body": "hi", "signed": false });
+/// assert!(serde_json::from_value::<Message>(unsigned).is_err());
+/// ```
+///
+/// An `Option` of a constant needs nothing special: serde's own implementation hands `Some`
+/// values to the constant, so a field can be required to be `true` if it's there at all.
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_constant::ConstBool;
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Flags {
+///     #[serde(default, skip_serializing_if = "Option::is_none")]
+///     beta: Option<ConstBool<true>>,
+/// }
+///
+/// // present and true
+/// let flags: Flags = serde_json::from_value(json!({ "beta": true })).unwrap();
+/// assert_eq!(flags.beta, Some(ConstBool));
+/// assert_eq!(serde_json::to_value(&flags).unwrap(), json!({ "beta": true }));
+///
+/// // present and false
+/// assert_eq!(
+///     serde_json::from_value::<Flags>(json!({ "beta": false })).unwrap_err().to_string(),
+///     "invalid value: boolean `false`, expected the constant bool true",
+/// );
+///
+/// // absent, or null
+/// let flags: Flags = serde_json::from_value(json!({})).unwrap();
+/// assert_eq!(flags.beta, None);
+/// assert_eq!(serde_json::to_value(&flags).unwrap(), json!({}));
+/// assert_eq!(serde_json::from_value::<Flags>(json!({ "beta": null })).unwrap(), flags);
+/// ```
+///
+/// Like all the primitive constants, the value is available in const contexts through
+/// [`ConstBool::VALUE`] and [`ConstBool::get`].
+///
+/// ```
+/// use serde_constant::ConstBool;
+/// const ENABLED: bool = ConstBool::<true>::VALUE;
+/// assert!(ENABLED);
+/// assert!(ConstBool::<true>.get());
+/// ```
+///
+/// It also implements [`Display`](fmt::Display), which writes the underlying value.
+///
+/// ```
+/// use serde_constant::ConstBool;
+/// assert_eq!(format!("{}", ConstBool::<true>), "true");
+/// ```
+///
+/// [`Debug`](fmt::Debug) includes the value too.
+///
+/// ```
+/// use serde_constant::ConstBool;
+/// assert_eq!(format!("{:?}", ConstBool::<true>), "ConstBool(true)");
+/// ```
+///
+/// It can be compared directly with a `bool`.
+///
+/// ```
+/// use serde_constant::ConstBool;
+/// assert!(ConstBool::<true> == true);
+/// assert!(false != ConstBool::<true>);
+/// ```
+///
+/// It can be ordered against a `bool` too.
+///
+/// ```
+/// use serde_constant::ConstBool;
+/// assert!(ConstBool::<false> < true);
+/// assert!(true > ConstBool::<false>);
+/// ```
+///
+/// Constants behind references and smart pointers work through serde's own implementations.
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstBool;
+/// use std::{rc::Rc, sync::Arc};
+/// assert_eq!(serde_json::to_value(&ConstBool::<true>).unwrap(), json!(true));
+/// assert_eq!(serde_json::to_value(Box::new(ConstBool::<true>)).unwrap(), json!(true));
+///
+/// let b: Box<ConstBool<true>> = serde_json::from_value(json!(true)).unwrap();
+/// assert_eq!(*b, ConstBool::<true>);
+/// assert!(serde_json::from_value::<Box<ConstBool<true>>>(json!(false)).is_err());
+/// // Rc and Arc need serde's `rc` feature
+/// assert!(serde_json::from_value::<Rc<ConstBool<true>>>(json!(true)).is_ok());
+/// assert!(serde_json::from_value::<Arc<ConstBool<true>>>(json!(false)).is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstBool<const V: bool>;
+
+impl<const V: bool> ConstBool<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstBool;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+
+    /// The underlying value.
+    pub const VALUE: bool = V;
+
+    /// Returns the underlying value.
+    pub const fn get(self) -> bool {
+        Self::VALUE
+    }
+}
+
+impl<const V: bool> ConstValue for ConstBool<V> {
+    type Repr = bool;
+    const VALUE: bool = V;
+}
+
+/// ```
+/// use serde_constant::ConstBool;
+/// let b: bool = ConstBool::<true>.into();
+/// assert!(b);
+/// ```
+impl<const V: bool> From<ConstBool<V>> for bool {
+    fn from(_: ConstBool<V>) -> bool {
+        V
+    }
+}
+
+impl<const V: bool> From<&ConstBool<V>> for bool {
+    fn from(_: &ConstBool<V>) -> bool {
+        V
+    }
+}
+
+impl<const V: bool> TryFrom<bool> for ConstBool<V> {
+    type Error = ConstMismatch<bool>;
+    fn try_from(value: bool) -> Result<Self, Self::Error> {
+        if value == V {
+            Ok(ConstBool::<V>)
+        } else {
+            Err(ConstMismatch {
+                expected: V,
+                found: value,
+            })
+        }
+    }
+}
+
+impl<const V: bool> FromStr for ConstBool<V> {
+    type Err = ParseConstError<bool>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_const(s)
+    }
+}
+
+impl<const V: bool> PartialEq<bool> for ConstBool<V> {
+    fn eq(&self, other: &bool) -> bool {
+        V == *other
+    }
+}
+
+impl<const V: bool> PartialEq<ConstBool<V>> for bool {
+    fn eq(&self, _: &ConstBool<V>) -> bool {
+        *self == V
+    }
+}
+
+impl<const V: bool> PartialOrd<bool> for ConstBool<V> {
+    fn partial_cmp(&self, other: &bool) -> Option<Ordering> {
+        Some(V.cmp(other))
+    }
+}
+
+impl<const V: bool> PartialOrd<ConstBool<V>> for bool {
+    fn partial_cmp(&self, _: &ConstBool<V>) -> Option<Ordering> {
+        Some(self.cmp(&V))
+    }
+}
+
+impl<const V: bool> fmt::Debug for ConstBool<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstBool").field(&V).finish()
+    }
+}
+
+impl<const V: bool> fmt::Display for ConstBool<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{V}")
+    }
+}
+
+impl<const V: bool> Serialize for ConstBool<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(V)
+    }
+}
+
+impl<'de, const V: bool> Deserialize<'de> for ConstBool<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // hinted deserialize calls never hand strings or integers to the visitor
+        #[cfg(any(
+            feature = "lenient-strings",
+            feature = "lenient-ints",
+            feature = "yaml-bools"
+        ))]
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_any(ConstBoolVisitor::<V>);
+        }
+        deserializer.deserialize_bool(ConstBoolVisitor::<V>)
+    }
+}
+
+impl<'de, const V: bool> DeserializeAny<'de> for ConstBool<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstBoolVisitor::<V>)
+    }
+}
+
+impl<'de, const V: bool, E: de::Error> IntoDeserializer<'de, E> for ConstBool<V> {
+    type Deserializer = <bool as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        V.into_deserializer()
+    }
+}
+
+struct ConstBoolVisitor<const V: bool>;
+
+impl<'de, const V: bool> Visitor<'de> for ConstBoolVisitor<V> {
+    type Value = ConstBool<V>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant bool {}", ConstBool::<V>)
+    }
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v == V {
+            Ok(ConstBool::<V>)
+        } else {
+            Err(E::invalid_value(Unexpected::Bool(v), &self))
+        }
+    }
+    #[cfg(any(feature = "lenient-strings", feature = "yaml-bools"))]
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        #[cfg(feature = "yaml-bools")]
+        if let Some(b) = yaml_bool(v) {
+            return self.visit_bool(b);
+        }
+        #[cfg(feature = "lenient-strings")]
+        match v {
+            "true" => return self.visit_bool(true),
+            "false" => return self.visit_bool(false),
+            _ => {}
+        }
+        Err(E::invalid_value(Unexpected::Str(v), &self))
+    }
+    #[cfg(any(feature = "lenient-strings", feature = "yaml-bools"))]
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+    #[cfg(feature = "lenient-ints")]
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_i128(v as i128)
+    }
+    #[cfg(feature = "lenient-ints")]
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // 0 is false and 1 is true
+        check_i128(v, V as i128, &self).map(|()| ConstBool::<V>)
+    }
+    #[cfg(feature = "lenient-ints")]
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_u128(v as u128)
+    }
+    #[cfg(feature = "lenient-ints")]
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        check_u128(v, V as u128, &self).map(|()| ConstBool::<V>)
+    }
+}
+
+/// Reads one of the YAML 1.1 boolean tokens, ignoring case.
+#[cfg(feature = "yaml-bools")]
+fn yaml_bool(v: &str) -> Option<bool> {
+    const TRUE: [&str; 4] = ["y", "yes", "true", "on"];
+    const FALSE: [&str; 4] = ["n", "no", "false", "off"];
+    if TRUE.iter().any(|t| t.eq_ignore_ascii_case(v)) {
+        Some(true)
+    } else if FALSE.iter().any(|t| t.eq_ignore_ascii_case(v)) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Like `E::invalid_value(Unexpected::Signed(v), exp)`, but without truncating `v` to 64 bits.
+///
+/// With the `alloc` feature, this is instead a single "expected ..., found ..." message.
+fn invalid_i128<E: de::Error>(v: i128, exp: &dyn Expected) -> E {
+    #[cfg(feature = "alloc")]
+    return E::custom(alloc::format!("expected {exp}, found {v}"));
+    #[cfg(not(feature = "alloc"))]
+    match i64::try_from(v) {
+        Ok(v) => E::invalid_value(Unexpected::Signed(v), exp),
+        Err(_) => E::invalid_value(Unexpected::Other(IntBuf::new(v).as_str()), exp),
+    }
+}
+
+/// Like `E::invalid_value(Unexpected::Unsigned(v), exp)`, but without truncating `v` to 64 bits.
+///
+/// With the `alloc` feature, this is instead a single "expected ..., found ..." message.
+fn invalid_u128<E: de::Error>(v: u128, exp: &dyn Expected) -> E {
+    #[cfg(feature = "alloc")]
+    return E::custom(alloc::format!("expected {exp}, found {v}"));
+    #[cfg(not(feature = "alloc"))]
+    match u64::try_from(v) {
+        Ok(v) => E::invalid_value(Unexpected::Unsigned(v), exp),
+        Err(_) => E::invalid_value(Unexpected::Other(IntBuf::new(v).as_str()), exp),
+    }
+}
+
+/// A stack buffer describing a 128-bit integer the way `Unexpected::Signed` would, for
+/// `Unexpected::Other`, which only takes a `&str`.
+#[cfg(not(feature = "alloc"))]
+struct IntBuf {
+    // `i128::MIN` takes 40 bytes, plus 10 for "integer ``"
+    buf: [u8; 50],
+    len: usize,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl IntBuf {
+    fn new(v: impl fmt::Display) -> IntBuf {
+        let mut buf = IntBuf {
+            buf: [0; 50],
+            len: 0,
+        };
+        // every 128-bit integer fits, so this can't fail
+        let _ = fmt::Write::write_fmt(&mut buf, format_args!("integer `{v}`"));
+        buf
+    }
+
+    fn as_str(&self) -> &str {
+        // only whole `str`s are ever written
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl fmt::Write for IntBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let dest = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Like [`invalid_u128`], but names the variant of a `const_enum_repr!` enum that `v` stands for.
+fn invalid_variant<E: de::Error>(v: u128, variant: &str, exp: &dyn Expected) -> E {
+    #[cfg(feature = "alloc")]
+    return E::custom(alloc::format!("expected {exp}, found {variant} ({v})"));
+    #[cfg(not(feature = "alloc"))]
+    E::custom(format_args!(
+        "invalid value: {variant} ({v}), expected {exp}"
+    ))
+}
+
+/// Like `E::invalid_value(Unexpected::Str(v), exp)` for a string that should have been a single
+/// char, but calls out `chars` being one char followed only by combining marks, like a decomposed
+/// `"e\u{301}"`, which looks like a single char but isn't one.
+fn invalid_char_str<E: de::Error>(v: &str, chars: &str, exp: &dyn Expected) -> E {
+    let mut chs = chars.chars();
+    if chs.next().is_some_and(|ch| !is_combining_mark(ch)) && chs.all(is_combining_mark) {
+        let len = chars.chars().count();
+        E::custom(format_args!(
+            "invalid value: grapheme cluster of {len} code points, expected {exp}"
+        ))
+    } else {
+        E::invalid_value(Unexpected::Str(v), exp)
+    }
+}
+
+/// Whether `ch` extends the char before it into one grapheme cluster.
+///
+/// This only covers the combining diacritic blocks, variation selectors, and emoji skin tones,
+/// rather than the full Unicode tables, which is enough for the accents and emoji that show up in
+/// practice.
+fn is_combining_mark(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{300}'..='\u{36F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+    )
+}
+
+/// Checks that `found` is `expected`.
+///
+/// The visitors forward here instead of comparing inline, so that each constant's visitor doesn't
+/// carry its own copy of the error path.
+fn check_i128<E: de::Error>(found: i128, expected: i128, exp: &dyn Expected) -> Result<(), E> {
+    if found == expected {
+        Ok(())
+    } else {
+        Err(invalid_i128(found, exp))
+    }
+}
+
+/// Checks that `found` is `expected`. See [`check_i128`].
+fn check_u128<E: de::Error>(found: u128, expected: u128, exp: &dyn Expected) -> Result<(), E> {
+    if found == expected {
+        Ok(())
+    } else {
+        Err(invalid_u128(found, exp))
+    }
+}
+
+/// Converts `f` to an `i128` if it is exactly an integer in range.
+fn f64_to_i128(f: f64) -> Option<i128> {
+    // `i128::MAX as f64` rounds up to 2^127, which is out of range. NaN fails both comparisons.
+    if f >= i128::MIN as f64 && f < i128::MAX as f64 {
+        let truncated = f as i128;
+        (truncated as f64 == f).then_some(truncated)
+    } else {
+        None
+    }
+}
+
+/// Converts `f` to a `u128` if it is exactly an integer in range.
+#[cfg(feature = "accept-whole-floats")]
+fn f64_to_u128(f: f64) -> Option<u128> {
+    // `u128::MAX as f64` rounds up to 2^128, which is out of range. NaN fails both comparisons.
+    if f >= 0.0 && f < u128::MAX as f64 {
+        let truncated = f as u128;
+        (truncated as f64 == f).then_some(truncated)
+    } else {
+        None
+    }
+}
+
+/// The key of the map that `serde_json` hands to `visit_map` for a number with its
+/// `arbitrary_precision` feature enabled, mapping to the number's digits as a string.
+#[cfg(feature = "serde-json-arbitrary-precision")]
+const ARBITRARY_PRECISION_TOKEN: &str = "$serde_json::private::Number";
+
+/// Unwraps a number that `serde_json` produced with its `arbitrary_precision` feature, and passes
+/// it to `visitor` as the narrowest type that holds it.
+#[cfg(feature = "serde-json-arbitrary-precision")]
+fn visit_arbitrary_precision<'de, V, A>(visitor: V, mut map: A) -> Result<V::Value, A::Error>
+where
+    V: Visitor<'de>,
+    A: de::MapAccess<'de>,
+{
+    // any other map is reported as a map, not as a problem with its first key
+    match map.next_key_seed(ArbitraryPrecisionKey)? {
+        Some(true) => map.next_value_seed(ArbitraryPrecision(visitor)),
+        _ => Err(de::Error::invalid_type(Unexpected::Map, &visitor)),
+    }
+}
+
+/// Checks whether a map key is [`ARBITRARY_PRECISION_TOKEN`].
+#[cfg(feature = "serde-json-arbitrary-precision")]
+struct ArbitraryPrecisionKey;
+
+#[cfg(feature = "serde-json-arbitrary-precision")]
+impl<'de> de::DeserializeSeed<'de> for ArbitraryPrecisionKey {
+    type Value = bool;
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde-json-arbitrary-precision")]
+impl<'de> Visitor<'de> for ArbitraryPrecisionKey {
+    type Value = bool;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map key")
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(v == ARBITRARY_PRECISION_TOKEN)
+    }
+}
+
+/// Parses the digits of an arbitrary-precision number. See [`visit_arbitrary_precision`].
+#[cfg(feature = "serde-json-arbitrary-precision")]
+struct ArbitraryPrecision<V>(V);
+
+#[cfg(feature = "serde-json-arbitrary-precision")]
+impl<'de, V: Visitor<'de>> de::DeserializeSeed<'de> for ArbitraryPrecision<V> {
+    type Value = V::Value;
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde-json-arbitrary-precision")]
+impl<'de, V: Visitor<'de>> Visitor<'de> for ArbitraryPrecision<V> {
+    type Value = V::Value;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.expecting(formatter)
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if let Ok(v) = v.parse::<i64>() {
+            self.0.visit_i64(v)
+        } else if let Ok(v) = v.parse::<u64>() {
+            self.0.visit_u64(v)
+        } else if let Ok(v) = v.parse::<i128>() {
+            self.0.visit_i128(v)
+        } else if let Ok(v) = v.parse::<u128>() {
+            self.0.visit_u128(v)
+        } else if let Ok(v) = v.parse::<f64>() {
+            self.0.visit_f64(v)
+        } else {
+            Err(E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+}
+
+macro_rules! declare_int {
+    ($($(#[$attr:meta])* $type:ty => $struct:ident $visitor:ident $ser_func:ident $deser_func:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("A const `", stringify!($type), "`.")]
+            ///
+            /// Deserialization fails if the value is not `V`.
+            $(#[$attr])*
+            #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+            pub struct $struct<const V: $type>;
+
+            impl<const V: $type> $struct<V> {
+                /// The constant, for `const` items and struct literals.
+                pub const NEW: Self = $struct;
+
+                /// Returns the constant.
+                pub const fn new() -> Self {
+                    Self::NEW
+                }
+
+                /// The underlying value.
+                pub const VALUE: $type = V;
+
+                /// Returns the underlying value.
+                pub const fn get(self) -> $type {
+                    Self::VALUE
+                }
+            }
+
+            impl<const V: $type> ConstValue for $struct<V> {
+                type Repr = $type;
+                const VALUE: $type = V;
+            }
+
+            /// ```
+            #[doc = concat!("use serde_constant::", stringify!($struct), ";")]
+            #[doc = concat!("let v: ", stringify!($type), " = ", stringify!($struct), "::<7>.into();")]
+            /// assert_eq!(v, 7);
+            /// ```
+            impl<const V: $type> From<$struct<V>> for $type {
+                fn from(_: $struct<V>) -> $type {
+                    V
+                }
+            }
+
+            /// ```
+            #[doc = concat!("use serde_constant::", stringify!($struct), ";")]
+            #[doc = concat!("let v: ", stringify!($type), " = (&", stringify!($struct), "::<7>).into();")]
+            /// assert_eq!(v, 7);
+            /// ```
+            impl<const V: $type> From<&$struct<V>> for $type {
+                fn from(_: &$struct<V>) -> $type {
+                    V
+                }
+            }
+
+            impl<const V: $type> TryFrom<$type> for $struct<V> {
+                type Error = ConstMismatch<$type>;
+                fn try_from(value: $type) -> Result<Self, Self::Error> {
+                    if value == V {
+                        Ok($struct::<V>)
+                    } else {
+                        Err(ConstMismatch {
+                            expected: V,
+                            found: value,
+                        })
+                    }
+                }
+            }
+
+            impl<const V: $type> FromStr for $struct<V> {
+                type Err = ParseConstError<$type>;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_const(s)
+                }
+            }
+
+            impl<const V: $type> PartialEq<$type> for $struct<V> {
+                fn eq(&self, other: &$type) -> bool {
+                    V == *other
+                }
+            }
+
+            impl<const V: $type> PartialEq<$struct<V>> for $type {
+                fn eq(&self, _: &$struct<V>) -> bool {
+                    *self == V
+                }
+            }
+
+            impl<const V: $type> PartialOrd<$type> for $struct<V> {
+                fn partial_cmp(&self, other: &$type) -> Option<Ordering> {
+                    Some(V.cmp(other))
+                }
+            }
+
+            impl<const V: $type> PartialOrd<$struct<V>> for $type {
+                fn partial_cmp(&self, _: &$struct<V>) -> Option<Ordering> {
+                    Some(self.cmp(&V))
+                }
+            }
+
+            impl<const V: $type> fmt::Debug for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($struct)).field(&V).finish()
+                }
+            }
+
+            impl<const V: $type> fmt::Display for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{V}")
+                }
+            }
+
+            impl<const V: $type> fmt::LowerHex for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::LowerHex::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> fmt::UpperHex for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::UpperHex::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> fmt::Binary for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Binary::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> fmt::Octal for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Octal::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> Serialize for $struct<V> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.$ser_func(V as _)
+                }
+            }
+
+            impl<const V: $type> $struct<V> {
+                /// Deserializes the constant, naming the field `label` in the expected value.
+                fn deserialize_labeled<'de, D>(
+                    deserializer: D,
+                    label: Option<&'static str>,
+                ) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    // hinted deserialize calls never hand strings to the visitor, and `serde_json`
+                    // rejects arbitrary-precision numbers that aren't already integers
+                    #[cfg(any(
+                        feature = "lenient-strings",
+                        feature = "bool-as-int",
+                        feature = "serde-json-arbitrary-precision",
+                    ))]
+                    if deserializer.is_human_readable() {
+                        return deserializer.deserialize_any($visitor::<V>(label));
+                    }
+                    deserializer.$deser_func($visitor::<V>(label))
+                }
+
+                /// Like [`Self::deserialize_labeled`], but self-describing.
+                fn deserialize_any_labeled<'de, D>(
+                    deserializer: D,
+                    label: Option<&'static str>,
+                ) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_any($visitor::<V>(label))
+                }
+            }
+
+            impl<'de, const V: $type> Deserialize<'de> for $struct<V> {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Self::deserialize_labeled(deserializer, None)
+                }
+            }
+
+            impl<'de, const V: $type> DeserializeAny<'de> for $struct<V> {
+                fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Self::deserialize_any_labeled(deserializer, None)
+                }
+            }
+
+            impl<'de, const V: $type, E: de::Error> IntoDeserializer<'de, E> for $struct<V> {
+                type Deserializer = <$type as IntoDeserializer<'de, E>>::Deserializer;
+                fn into_deserializer(self) -> Self::Deserializer {
+                    V.into_deserializer()
+                }
+            }
+
+            struct $visitor<const V: $type>(Option<&'static str>);
+
+            impl<'de, const V: $type> Visitor<'de> for $visitor<V> {
+                type Value = $struct<V>;
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, concat!("the constant ", stringify!($type), " {}"), $struct::<V>)?;
+                    match self.0 {
+                        Some(label) => write!(formatter, " for field {label:?}"),
+                        None => Ok(()),
+                    }
+                }
+                fn visit_i8<E>(self, v: i8) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_i16<E>(self, v: i16) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    check_i128(v, V as i128, &self).map(|()| $struct::<V>)
+                }
+                fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    // anything beyond `i128::MAX` is out of range for every signed constant
+                    match i128::try_from(v) {
+                        Ok(v) => self.visit_i128(v),
+                        Err(_) => Err(invalid_u128(v, &self)),
+                    }
+                }
+                fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_f64(v as f64)
+                }
+                fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    // whole numbers only match with `accept-whole-floats`, and other floats are the wrong
+                    // type entirely
+                    #[cfg(feature = "accept-whole-floats")]
+                    return match f64_to_i128(v) {
+                        Some(v) => self.visit_i128(v),
+                        None => Err(E::invalid_value(Unexpected::Float(v), &self)),
+                    };
+                    #[cfg(not(feature = "accept-whole-floats"))]
+                    Err(E::invalid_type(Unexpected::Float(v), &self))
+                }
+                #[cfg(feature = "bool-as-int")]
+                fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    // `true` is 1 and `false` is 0
+                    if v as i128 == V as i128 {
+                        Ok($struct::<V>)
+                    } else {
+                        Err(E::invalid_value(Unexpected::Bool(v), &self))
+                    }
+                }
+                #[cfg(feature = "lenient-strings")]
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    match v.parse::<i128>() {
+                        Ok(parsed) => self.visit_i128(parsed),
+                        Err(_) => Err(E::invalid_value(Unexpected::Str(v), &self)),
+                    }
+                }
+                #[cfg(feature = "serde-json-arbitrary-precision")]
+                fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: de::MapAccess<'de>,
+                {
+                    visit_arbitrary_precision(self, map)
+                }
+            }
+        )*
+    };
+}
+
+declare_int!(
+    ///
+    /// # Examples
+    ///
+    /// Values of every integer type are compared by value, not by bit pattern:
+    ///
+    /// ```
+    /// use serde_constant::ConstI8;
+    /// use serde_test::{assert_de_tokens, assert_de_tokens_error, Compact, Configure, Token};
+    /// assert_de_tokens(&ConstI8::<-1>.compact(), &[Token::I8(-1)]);
+    /// assert_de_tokens(&ConstI8::<-1>.compact(), &[Token::I64(-1)]);
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_de_tokens_error::<Compact<ConstI8<-1>>>(
+    ///     &[Token::U8(255)],
+    ///     "invalid value: integer `255`, expected the constant i8 -1",
+    /// );
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_de_tokens_error::<Compact<ConstI8<-1>>>(
+    ///     &[Token::U64(u64::MAX)],
+    ///     "invalid value: integer `18446744073709551615`, expected the constant i8 -1",
+    /// );
+    /// ```
+    ///
+    /// Integer constants implement the radix formatting traits, which write negative values in
+    /// two's complement like the primitives do.
+    ///
+    /// ```
+    /// use serde_constant::ConstI8;
+    /// assert_eq!(format!("{:x}", ConstI8::<-1>), "ff");
+    /// assert_eq!(format!("{:#X}", ConstI8::<-2>), "0xFE");
+    /// assert_eq!(format!("{:08b}", ConstI8::<-128>), "10000000");
+    /// assert_eq!(format!("{:#o}", ConstI8::<-1>), "0o377");
+    /// ```
+    i8 => ConstI8 ConstI8Visitor serialize_i8 deserialize_i8,
+    i16 => ConstI16 ConstI16Visitor serialize_i16 deserialize_i16,
+    i32 => ConstI32 ConstI32Visitor serialize_i32 deserialize_i32,
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json::{from_value, json};
+    /// use serde_constant::ConstI64;
+    /// assert!(from_value::<ConstI64<2>>(json!(2)).is_ok());
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_eq!(
+    ///     from_value::<ConstI64<2>>(json!(5)).unwrap_err().to_string(),
+    ///     "invalid value: integer `5`, expected the constant i64 2",
+    /// );
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(
+    ///     from_value::<ConstI64<2>>(json!(5)).unwrap_err().to_string(),
+    ///     "expected the constant i64 2, found 5",
+    /// );
+    ///
+    /// // floats are the wrong type
+    /// # #[cfg(not(feature = "accept-whole-floats"))] {
+    /// assert_eq!(
+    ///     from_value::<ConstI64<2>>(json!(2.5)).unwrap_err().to_string(),
+    ///     "invalid type: floating point `2.5`, expected the constant i64 2",
+    /// );
+    /// assert!(from_value::<ConstI64<2>>(json!(2.0)).is_err());
+    /// # }
+    ///
+    /// // unless the `accept-whole-floats` feature is on, which accepts whole numbers
+    /// # #[cfg(feature = "accept-whole-floats")] {
+    /// assert!(from_value::<ConstI64<2>>(json!(2.0)).is_ok());
+    /// assert_eq!(
+    ///     from_value::<ConstI64<2>>(json!(2.5)).unwrap_err().to_string(),
+    ///     "invalid value: floating point `2.5`, expected the constant i64 2",
+    /// );
+    /// # }
+    ///
+    /// // other types of values name the constant too
+    /// assert_eq!(
+    ///     from_value::<ConstI64<2>>(json!({ "value": 2 })).unwrap_err().to_string(),
+    ///     "invalid type: map, expected the constant i64 2",
+    /// );
+    /// assert_eq!(
+    ///     from_value::<ConstI64<2>>(json!([2])).unwrap_err().to_string(),
+    ///     "invalid type: sequence, expected the constant i64 2",
+    /// );
+    /// ```
+    ///
+    /// With the `bool-as-int` feature, human-readable formats can deliver `true` for 1 and `false`
+    /// for 0, as some legacy APIs do.
+    ///
+    /// ```
+    /// # use serde_json::{from_value, json};
+    /// use serde_constant::{ConstI64, ConstU8};
+    /// let legacy = from_value::<ConstI64<1>>(json!(true));
+    /// # #[cfg(feature = "bool-as-int")] {
+    /// assert!(legacy.is_ok());
+    /// assert_eq!(
+    ///     from_value::<ConstI64<1>>(json!(false)).unwrap_err().to_string(),
+    ///     "invalid value: boolean `false`, expected the constant i64 1",
+    /// );
+    /// assert!(from_value::<ConstU8<0>>(json!(false)).is_ok());
+    /// assert!(from_value::<ConstI64<2>>(json!(true)).is_err());
+    /// assert!(from_value::<ConstI64<1>>(json!(1)).is_ok());
+    /// # }
+    /// # #[cfg(not(feature = "bool-as-int"))]
+    /// assert_eq!(
+    ///     legacy.unwrap_err().to_string(),
+    ///     "invalid type: boolean `true`, expected the constant i64 1",
+    /// );
+    /// ```
+    ///
+    /// Under `#[serde(flatten)]`, serde buffers the field before handing it over, and the
+    /// constant is still checked.
+    ///
+    /// ```
+    /// # use serde::{Deserialize, Serialize};
+    /// # use serde_json::json;
+    /// use serde_constant::{ConstI64};
+    /// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    /// struct Header {
+    ///     version: ConstI64<2>,
+    /// }
+    ///
+    /// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    /// struct Message {
+    ///     body: String,
+    ///     #[serde(flatten)]
+    ///     header: Header,
+    /// }
+    ///
+    /// let json = r#"{"body":"hi","version":2}"#;
+    /// let message: Message = serde_json::from_str(json).unwrap();
+    /// assert_eq!(serde_json::to_string(&message).unwrap(), json);
+    /// assert!(serde_json::from_value::<Message>(json!({ "body": "hi", "version": 1 })).is_err());
+    /// ```
+    ///
+    /// Behind references and smart pointers, serde's own implementations defer to the constant.
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// use serde_constant::ConstI64;
+    /// use std::sync::Arc;
+    /// assert_eq!(serde_json::to_value(&ConstI64::<2>).unwrap(), json!(2));
+    /// assert_eq!(serde_json::to_value(Box::new(ConstI64::<2>)).unwrap(), json!(2));
+    /// assert!(serde_json::from_value::<Box<ConstI64<2>>>(json!(2)).is_ok());
+    /// assert!(serde_json::from_value::<Arc<ConstI64<2>>>(json!(3)).is_err());
+    /// ```
+    ///
+    /// It hashes the same as its underlying value, so different constants of the same type hash
+    /// differently.
+    ///
+    /// ```
+    /// use serde_constant::{ConstI64, ConstValue};
+    /// use std::hash::{BuildHasher, Hash, RandomState};
+    /// fn hash_of<T: ConstValue + Hash>(state: &RandomState, constant: T) -> u64 {
+    ///     state.hash_one(constant)
+    /// }
+    ///
+    /// let state = RandomState::new();
+    /// assert_ne!(hash_of(&state, ConstI64::<1>), hash_of(&state, ConstI64::<2>));
+    /// assert_eq!(hash_of(&state, ConstI64::<2>), state.hash_one(2i64));
+    /// ```
+    ///
+    /// Like every constant, it can also be turned into a [`Deserializer`] that produces its value:
+    ///
+    /// ```
+    /// # use serde::{de::{value, IntoDeserializer}, Deserialize};
+    /// use serde_constant::ConstI64;
+    /// let v = i64::deserialize(IntoDeserializer::<value::Error>::into_deserializer(ConstI64::<2>));
+    /// assert_eq!(v, Ok(2));
+    ///
+    /// #[derive(Deserialize, Debug, PartialEq)]
+    /// #[serde(transparent)]
+    /// struct Version(i64);
+    /// let version = Version::deserialize(ConstI64::<2>.into_deserializer());
+    /// assert_eq!(version, Ok::<_, value::Error>(Version(2)));
+    /// ```
+    ///
+    /// Every integer is compared as an `i128`, so a value matches whichever `visit_*` method the
+    /// format calls. `i64::MIN` relies on `visit_i64` or `visit_i128`, and `i64::MAX` also
+    /// arrives intact through `visit_u64` or `visit_u128`. Only `visit_u128` can carry values
+    /// beyond `i128::MAX`, and those never match.
+    ///
+    /// ```
+    /// # use serde::{de::{value, IntoDeserializer}, Deserialize};
+    /// use serde_constant::{ConstI128, ConstI64};
+    /// fn from<'de, T: Deserialize<'de>>(v: impl IntoDeserializer<'de, value::Error>) -> bool {
+    ///     T::deserialize(v.into_deserializer()).is_ok()
+    /// }
+    ///
+    /// type Min = ConstI64<{ i64::MIN }>;
+    /// assert!(from::<Min>(i64::MIN));
+    /// assert!(from::<Min>(i64::MIN as i128));
+    /// assert!(!from::<Min>(i64::MIN as i128 - 1));
+    /// assert!(!from::<Min>(i64::MIN as u64));
+    ///
+    /// type Max = ConstI64<{ i64::MAX }>;
+    /// assert!(from::<Max>(i64::MAX));
+    /// assert!(from::<Max>(i64::MAX as u64));
+    /// assert!(from::<Max>(i64::MAX as i128));
+    /// assert!(from::<Max>(i64::MAX as u128));
+    /// assert!(!from::<Max>(i64::MAX as u64 + 1));
+    ///
+    /// assert!(from::<ConstI128<{ i128::MIN }>>(i128::MIN));
+    /// assert!(!from::<ConstI128<{ i128::MIN }>>(i128::MIN as u128));
+    /// assert!(from::<ConstI128<{ i128::MAX }>>(i128::MAX));
+    /// assert!(from::<ConstI128<{ i128::MAX }>>(i128::MAX as u128));
+    /// assert!(!from::<ConstI128<{ i128::MAX }>>(i128::MAX as u128 + 1));
+    /// ```
+    ///
+    /// With `serde_json`'s `arbitrary_precision` feature, numbers that don't fit in an `i64` or
+    /// `u64`, like `-0` or `2.0`, reach `deserialize_any` as a map holding their digits. The
+    /// `serde-json-arbitrary-precision` feature accepts that map, though `2.0` still needs
+    /// `accept-whole-floats`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde-json-arbitrary-precision")] {
+    /// # use serde::Deserialize;
+    /// use serde_constant::{ConstI128, ConstI64};
+    /// #[derive(Deserialize, Debug)]
+    /// #[serde(untagged)]
+    /// enum Amount {
+    ///     Zero { cents: ConstI64<0> },
+    ///     Two { cents: ConstI64<2> },
+    ///     Huge { cents: ConstI128<{ 1 << 100 }> },
+    /// }
+    ///
+    /// let from_str = serde_json::from_str::<Amount>;
+    /// assert!(matches!(from_str(r#"{ "cents": -0 }"#).unwrap(), Amount::Zero { .. }));
+    /// # #[cfg(feature = "accept-whole-floats")] {
+    /// assert!(matches!(from_str(r#"{ "cents": 2.0 }"#).unwrap(), Amount::Two { .. }));
+    /// assert!(matches!(from_str(r#"{ "cents": 2e0 }"#).unwrap(), Amount::Two { .. }));
+    /// # }
+    /// assert!(matches!(
+    ///     from_str(r#"{ "cents": 1267650600228229401496703205376 }"#).unwrap(),
+    ///     Amount::Huge { .. },
+    /// ));
+    /// assert!(from_str(r#"{ "cents": 2.5 }"#).is_err());
+    ///
+    /// // other maps are still rejected as maps
+    /// assert_eq!(
+    ///     serde_json::from_value::<ConstI64<2>>(serde_json::json!({ "cents": 2 }))
+    ///         .unwrap_err()
+    ///         .to_string(),
+    ///     "invalid type: map, expected the constant i64 2",
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// It can be compared directly with an `i64`.
+    ///
+    /// ```
+    /// use serde_constant::ConstI64;
+    /// assert!(ConstI64::<2> == 2);
+    /// assert!(2 == ConstI64::<2>);
+    /// ```
+    ///
+    /// It can be ordered against an `i64` too, e.g. for range checks.
+    ///
+    /// ```
+    /// use serde_constant::ConstI64;
+    /// assert!(ConstI64::<2> < 5);
+    /// assert!(ConstI64::<2> >= -3);
+    /// assert!(5 > ConstI64::<2>);
+    /// assert!(ConstI64::<2> <= 2);
+    /// ```
+    ///
+    /// [`Display`](fmt::Display) writes the underlying value.
+    ///
+    /// ```
+    /// use serde_constant::ConstI64;
+    /// assert_eq!(format!("{}", ConstI64::<-2>), "-2");
+    /// ```
+    ///
+    /// [`Debug`](fmt::Debug) includes the value too.
+    ///
+    /// ```
+    /// use serde_constant::ConstI64;
+    /// assert_eq!(format!("{:?}", ConstI64::<2>), "ConstI64(2)");
+    /// ```
+    i64 => ConstI64 ConstI64Visitor serialize_i64 deserialize_i64,
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde::{de::{value, IntoDeserializer}, Deserialize};
+    /// use serde_constant::ConstI128;
+    /// let err = ConstI128::<2>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(i128::MIN))
+    ///     .unwrap_err();
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid value: integer `-170141183460469231731687303715884105728`, expected the constant i128 2",
+    /// );
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "expected the constant i128 2, found -170141183460469231731687303715884105728",
+    /// );
+    ///
+    /// let err = ConstI128::<2>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(u128::MAX))
+    ///     .unwrap_err();
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid value: integer `340282366920938463463374607431768211455`, expected the constant i128 2",
+    /// );
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "expected the constant i128 2, found 340282366920938463463374607431768211455",
+    /// );
+    /// ```
+    ///
+    /// Values outside the 64-bit range are still reported through `invalid_value`, with the full
+    /// value formatted on the stack, so formats with their own error types see them without an
+    /// allocator.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "alloc"))] {
+    /// use serde::de::{self, Deserialize, Expected, IntoDeserializer, Unexpected};
+    /// use serde_constant::{ConstI128, ConstU128};
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct InvalidValue(String);
+    ///
+    /// impl de::Error for InvalidValue {
+    ///     fn custom<T: fmt::Display>(msg: T) -> Self {
+    ///         panic!("unexpected custom error: {msg}")
+    ///     }
+    ///     fn invalid_value(unexp: Unexpected, _: &dyn Expected) -> Self {
+    ///         InvalidValue(unexp.to_string())
+    ///     }
+    /// }
+    /// # impl fmt::Display for InvalidValue {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    /// #         f.write_str(&self.0)
+    /// #     }
+    /// # }
+    /// # impl std::error::Error for InvalidValue {}
+    ///
+    /// let err = ConstI128::<2>::deserialize(IntoDeserializer::<InvalidValue>::into_deserializer(i128::MIN))
+    ///     .unwrap_err();
+    /// assert_eq!(err.0, "integer `-170141183460469231731687303715884105728`");
+    /// let err = ConstU128::<2>::deserialize(IntoDeserializer::<InvalidValue>::into_deserializer(u128::MAX))
+    ///     .unwrap_err();
+    /// assert_eq!(err.0, "integer `340282366920938463463374607431768211455`");
+    /// # }
+    /// ```
+    ///
+    /// Unsigned input is compared as an `i128`, so `visit_u64` always widens losslessly and
+    /// `visit_u128` only fails above `i128::MAX`. Negative constants never match unsigned input.
+    ///
+    /// ```
+    /// # use serde::{de::{value, IntoDeserializer}, Deserialize};
+    /// use serde_constant::ConstI128;
+    /// fn from<'de, T: Deserialize<'de>>(v: impl IntoDeserializer<'de, value::Error>) -> bool {
+    ///     T::deserialize(v.into_deserializer()).is_ok()
+    /// }
+    ///
+    /// type U64Max = ConstI128<{ u64::MAX as i128 }>;
+    /// assert!(from::<U64Max>(u64::MAX));
+    /// assert!(from::<U64Max>(u64::MAX as u128));
+    /// assert!(!from::<U64Max>(u64::MAX - 1));
+    ///
+    /// type AboveU64 = ConstI128<{ u64::MAX as i128 + 1 }>;
+    /// assert!(from::<AboveU64>(u64::MAX as u128 + 1));
+    /// assert!(!from::<AboveU64>(u64::MAX));
+    ///
+    /// assert!(from::<ConstI128<{ i128::MAX }>>(i128::MAX as u128));
+    /// assert!(!from::<ConstI128<{ i128::MAX }>>(i128::MAX as u128 + 1));
+    ///
+    /// // the bits of `u64::MAX` and `u128::MAX` would read as -1 if wrapped
+    /// assert!(!from::<ConstI128<-1>>(u64::MAX));
+    /// assert!(!from::<ConstI128<-1>>(u128::MAX));
+    /// assert!(!from::<ConstI128<{ i128::MIN }>>(1u128 << 127));
+    /// assert!(from::<ConstI128<{ i128::MIN }>>(i128::MIN));
+    /// ```
+    ///
+    /// Binary formats carry the full 128 bits:
+    ///
+    /// ```
+    /// use serde_constant::ConstI128;
+    /// type Min = ConstI128<{ i128::MIN }>;
+    ///
+    /// let bytes = bincode::serialize(&Min::default()).unwrap();
+    /// assert_eq!(bincode::deserialize::<Min>(&bytes).unwrap(), Min::default());
+    /// assert!(bincode::deserialize::<ConstI128<2>>(&bytes).is_err());
+    ///
+    /// let bytes = postcard::to_allocvec(&Min::default()).unwrap();
+    /// assert_eq!(postcard::from_bytes::<Min>(&bytes).unwrap(), Min::default());
+    /// assert!(postcard::from_bytes::<ConstI128<2>>(&bytes).is_err());
+    /// ```
+    i128 => ConstI128 ConstI128Visitor serialize_i128 deserialize_i128,
+    ///
+    /// This is serialized as an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// use serde_constant::ConstIsize;
+    /// assert!(serde_json::from_value::<ConstIsize<{ i32::MIN as isize }>>(json!(i32::MIN)).is_ok());
+    /// # #[cfg(target_pointer_width = "64")]
+    /// assert!(serde_json::from_value::<ConstIsize<{ i64::MIN as isize }>>(json!(i64::MIN)).is_ok());
+    /// assert!(serde_json::from_value::<ConstIsize<-5>>(json!(-6)).is_err());
+    /// ```
+    isize => ConstIsize ConstIsizeVisitor serialize_i64 deserialize_i64,
+);
+
+macro_rules! declare_uint {
+    ($($(#[$attr:meta])* $type:ty => $struct:ident $visitor:ident $ser_func:ident $deser_func:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("A const `", stringify!($type), "`.")]
+            ///
+            ///  Deserialization fails if the value is not `V`.
+            $(#[$attr])*
+            #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+            pub struct $struct<const V: $type>;
+
+            impl<const V: $type> $struct<V> {
+                /// The constant, for `const` items and struct literals.
+                pub const NEW: Self = $struct;
+
+                /// Returns the constant.
+                pub const fn new() -> Self {
+                    Self::NEW
+                }
+
+                /// The underlying value.
+                pub const VALUE: $type = V;
+
+                /// Returns the underlying value.
+                pub const fn get(self) -> $type {
+                    Self::VALUE
+                }
+            }
+
+            impl<const V: $type> ConstValue for $struct<V> {
+                type Repr = $type;
+                const VALUE: $type = V;
+            }
+
+            /// ```
+            #[doc = concat!("use serde_constant::", stringify!($struct), ";")]
+            #[doc = concat!("let v: ", stringify!($type), " = ", stringify!($struct), "::<7>.into();")]
+            /// assert_eq!(v, 7);
+            /// ```
+            impl<const V: $type> From<$struct<V>> for $type {
+                fn from(_: $struct<V>) -> $type {
+                    V
+                }
+            }
+
+            /// ```
+            #[doc = concat!("use serde_constant::", stringify!($struct), ";")]
+            #[doc = concat!("let v: ", stringify!($type), " = (&", stringify!($struct), "::<7>).into();")]
+            /// assert_eq!(v, 7);
+            /// ```
+            impl<const V: $type> From<&$struct<V>> for $type {
+                fn from(_: &$struct<V>) -> $type {
+                    V
+                }
+            }
+
+            impl<const V: $type> TryFrom<$type> for $struct<V> {
+                type Error = ConstMismatch<$type>;
+                fn try_from(value: $type) -> Result<Self, Self::Error> {
+                    if value == V {
+                        Ok($struct::<V>)
+                    } else {
+                        Err(ConstMismatch {
+                            expected: V,
+                            found: value,
+                        })
+                    }
+                }
+            }
+
+            impl<const V: $type> FromStr for $struct<V> {
+                type Err = ParseConstError<$type>;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_const(s)
+                }
+            }
+
+            impl<const V: $type> PartialEq<$type> for $struct<V> {
+                fn eq(&self, other: &$type) -> bool {
+                    V == *other
+                }
+            }
+
+            impl<const V: $type> PartialEq<$struct<V>> for $type {
+                fn eq(&self, _: &$struct<V>) -> bool {
+                    *self == V
+                }
+            }
+
+            impl<const V: $type> PartialOrd<$type> for $struct<V> {
+                fn partial_cmp(&self, other: &$type) -> Option<Ordering> {
+                    Some(V.cmp(other))
+                }
+            }
+
+            impl<const V: $type> PartialOrd<$struct<V>> for $type {
+                fn partial_cmp(&self, _: &$struct<V>) -> Option<Ordering> {
+                    Some(self.cmp(&V))
+                }
+            }
+
+            impl<const V: $type> fmt::Debug for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($struct)).field(&V).finish()
+                }
+            }
+
+            impl<const V: $type> fmt::Display for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{V}")
+                }
+            }
+
+            impl<const V: $type> fmt::LowerHex for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::LowerHex::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> fmt::UpperHex for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::UpperHex::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> fmt::Binary for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Binary::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> fmt::Octal for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Octal::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> Serialize for $struct<V> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.$ser_func(V as _)
+                }
+            }
+
+            impl<'de, const V: $type> Deserialize<'de> for $struct<V> {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    // hinted deserialize calls never hand strings to the visitor, and `serde_json`
+                    // rejects arbitrary-precision numbers that aren't already integers
+                    #[cfg(any(
+                        feature = "lenient-strings",
+                        feature = "char-bytes",
+                        feature = "bool-as-int",
+                        feature = "serde-json-arbitrary-precision",
+                    ))]
+                    if deserializer.is_human_readable() {
+                        return deserializer.deserialize_any($visitor::<V>);
+                    }
+                    deserializer.$deser_func($visitor::<V>)
+                }
+            }
+
+            impl<'de, const V: $type> DeserializeAny<'de> for $struct<V> {
+                fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_any($visitor::<V>)
+                }
+            }
+
+            impl<'de, const V: $type, E: de::Error> IntoDeserializer<'de, E> for $struct<V> {
+                type Deserializer = <$type as IntoDeserializer<'de, E>>::Deserializer;
+                fn into_deserializer(self) -> Self::Deserializer {
+                    V.into_deserializer()
+                }
+            }
+
+            struct $visitor<const V: $type>;
+
+            impl<'de, const V: $type> Visitor<'de> for $visitor<V> {
+                type Value = $struct<V>;
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, concat!("the constant ", stringify!($type), " {}"), $struct::<V>)
+                }
+                fn visit_i8<E>(self, v: i8) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_i16<E>(self, v: i16) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_i128(v as i128)
+                }
+                fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    // every non-negative `i128` fits in `u128`
+                    if v < 0 {
+                        Err(invalid_i128(v, &self))
+                    } else {
+                        self.visit_u128(v as u128)
+                    }
+                }
+                fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_u128(v as u128)
+                }
+                fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_u128(v as u128)
+                }
+                fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_u128(v as u128)
+                }
+                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_u128(v as u128)
+                }
+                fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    check_u128(v, V as u128, &self).map(|()| $struct::<V>)
+                }
+                fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_f64(v as f64)
+                }
+                fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    // whole numbers only match with `accept-whole-floats`, and other floats are the wrong
+                    // type entirely
+                    #[cfg(feature = "accept-whole-floats")]
+                    return match f64_to_u128(v) {
+                        Some(v) => self.visit_u128(v),
+                        None => Err(E::invalid_value(Unexpected::Float(v), &self)),
+                    };
+                    #[cfg(not(feature = "accept-whole-floats"))]
+                    Err(E::invalid_type(Unexpected::Float(v), &self))
+                }
+                #[cfg(feature = "bool-as-int")]
+                fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    // `true` is 1 and `false` is 0
+                    if v as u128 == V as u128 {
+                        Ok($struct::<V>)
+                    } else {
+                        Err(E::invalid_value(Unexpected::Bool(v), &self))
+                    }
+                }
+                #[cfg(any(feature = "lenient-strings", feature = "char-bytes"))]
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    #[cfg(feature = "lenient-strings")]
+                    if let Ok(parsed) = v.parse::<u128>() {
+                        return self.visit_u128(parsed);
+                    }
+                    // only `ConstU8` reads a single ASCII char as its byte. A one-byte string is
+                    // always ASCII.
+                    #[cfg(feature = "char-bytes")]
+                    if let (8, &[b]) = (<$type>::BITS, v.as_bytes()) {
+                        if b as u128 == V as u128 {
+                            return Ok($struct::<V>);
+                        }
+                    }
+                    Err(E::invalid_value(Unexpected::Str(v), &self))
+                }
+                #[cfg(feature = "serde-json-arbitrary-precision")]
+                fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: de::MapAccess<'de>,
+                {
+                    visit_arbitrary_precision(self, map)
+                }
+            }
+        )*
+    };
+}
+
+declare_uint!(
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_constant::ConstU8;
+    /// use serde_test::{assert_de_tokens, assert_de_tokens_error, Compact, Configure, Token};
+    /// assert_de_tokens(&ConstU8::<255>.compact(), &[Token::U8(255)]);
+    /// assert_de_tokens(&ConstU8::<255>.compact(), &[Token::I16(255)]);
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_de_tokens_error::<Compact<ConstU8<255>>>(
+    ///     &[Token::I8(-1)],
+    ///     "invalid value: integer `-1`, expected the constant u8 255",
+    /// );
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_de_tokens_error::<Compact<ConstU8<255>>>(
+    ///     &[Token::U16(511)],
+    ///     "invalid value: integer `511`, expected the constant u8 255",
+    /// );
+    /// ```
+    ///
+    /// Constants can be widened to the next integer type of the same signedness, keeping their
+    /// value:
+    ///
+    /// ```
+    /// use serde_constant::{ConstI16, ConstI8, ConstU16, ConstU32, ConstU8};
+    /// let wide: ConstU16<5> = ConstU8::<5>.widen();
+    /// assert_eq!(wide, 5);
+    /// let wider: ConstU32<255> = ConstU8::<255>.widen::<255>().widen();
+    /// assert_eq!(wider, 255);
+    /// assert_eq!(ConstI8::<-1>.widen::<-1>(), ConstI16::<-1>);
+    /// ```
+    ///
+    /// A different value fails to compile.
+    ///
+    /// With the `char-bytes` feature, a string of a single ASCII char is accepted as its byte, for
+    /// fields documented by their letter. With `lenient-strings` too, digits are read as numbers,
+    /// so `"7"` is `7`, not `55`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "char-bytes")] {
+    /// # use serde_json::json;
+    /// use serde_constant::ConstU8;
+    /// type RecordA = ConstU8<65>;
+    /// assert!(serde_json::from_value::<RecordA>(json!("A")).is_ok());
+    /// assert!(serde_json::from_value::<RecordA>(json!(65)).is_ok());
+    /// assert!(serde_json::from_value::<RecordA>(json!("B")).is_err());
+    /// assert!(serde_json::from_value::<RecordA>(json!("AB")).is_err());
+    /// assert!(serde_json::from_value::<RecordA>(json!("")).is_err());
+    /// assert!(serde_json::from_value::<ConstU8<195>>(json!("Ã")).is_err());
+    /// assert_eq!(
+    ///     serde_json::from_value::<RecordA>(json!("B")).unwrap_err().to_string(),
+    ///     "invalid value: string \"B\", expected the constant u8 65",
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// Under `#[serde(flatten)]`, serde buffers the field before handing it over, and the
+    /// constant is still checked.
+    ///
+    /// ```
+    /// # use serde::{Deserialize, Serialize};
+    /// # use serde_json::json;
+    /// use serde_constant::{ConstU8};
+    /// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    /// struct Header {
+    ///     flags: ConstU8<3>,
+    /// }
+    ///
+    /// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    /// struct Message {
+    ///     body: String,
+    ///     #[serde(flatten)]
+    ///     header: Header,
+    /// }
+    ///
+    /// let json = r#"{"body":"hi","flags":3}"#;
+    /// let message: Message = serde_json::from_str(json).unwrap();
+    /// assert_eq!(serde_json::to_string(&message).unwrap(), json);
+    /// assert!(serde_json::from_value::<Message>(json!({ "body": "hi", "flags": 7 })).is_err());
+    /// ```
+    ///
+    /// It can be compared directly with a `u8`.
+    ///
+    /// ```
+    /// use serde_constant::ConstU8;
+    /// assert!(ConstU8::<2> != 3);
+    /// assert!(3 != ConstU8::<2>);
+    /// ```
+    ///
+    /// [`Display`](fmt::Display) writes the underlying value.
+    ///
+    /// ```
+    /// use serde_constant::ConstU8;
+    /// assert_eq!(format!("{}", ConstU8::<255>), "255");
+    /// ```
+    u8 => ConstU8 ConstU8Visitor serialize_u8 deserialize_u8,
+    ///
+    /// # Examples
+    ///
+    /// Integer constants implement the radix formatting traits, e.g. for register maps logged in
+    /// hex.
+    ///
+    /// ```
+    /// use serde_constant::ConstU16;
+    /// assert_eq!(format!("{:#x}", ConstU16::<0xBEEF>), "0xbeef");
+    /// assert_eq!(format!("{:X}", ConstU16::<0xBEEF>), "BEEF");
+    /// assert_eq!(format!("{:#b}", ConstU16::<5>), "0b101");
+    /// assert_eq!(format!("{:o}", ConstU16::<8>), "10");
+    /// ```
+    u16 => ConstU16 ConstU16Visitor serialize_u16 deserialize_u16,
+    u32 => ConstU32 ConstU32Visitor serialize_u32 deserialize_u32,
+    ///
+    /// # Examples
+    ///
+    /// With the `lenient-strings` feature, numeric strings are accepted as well:
+    ///
+    /// ```
+    /// # #[cfg(feature = "lenient-strings")] {
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// use serde_constant::ConstU64;
+    /// #[derive(Deserialize)]
+    /// struct Foo {
+    ///     id: ConstU64<123>,
+    /// }
+    ///
+    /// assert!(serde_json::from_value::<Foo>(json!({ "id": "123" })).is_ok());
+    /// assert!(serde_json::from_value::<Foo>(json!({ "id": 123 })).is_ok());
+    /// assert!(serde_json::from_value::<Foo>(json!({ "id": "124" })).is_err());
+    /// assert!(serde_json::from_value::<Foo>(json!({ "id": "12three" })).is_err());
+    /// assert!(serde_json::from_value::<Foo>(json!({ "id": "99999999999999999999999999999999999999999" })).is_err());
+    /// # }
+    /// ```
+    ///
+    /// Every integer is compared as a `u128`, so a value matches whichever `visit_*` method the
+    /// format calls. `u64::MAX` relies on `visit_u64`, `visit_i128`, or `visit_u128`, and
+    /// `u128::MAX` on `visit_u128` alone. Negative values are rejected before the comparison.
+    ///
+    /// ```
+    /// # use serde::{de::{value, IntoDeserializer}, Deserialize};
+    /// use serde_constant::{ConstU128, ConstU64};
+    /// fn from<'de, T: Deserialize<'de>>(v: impl IntoDeserializer<'de, value::Error>) -> bool {
+    ///     T::deserialize(v.into_deserializer()).is_ok()
+    /// }
+    ///
+    /// type Max = ConstU64<{ u64::MAX }>;
+    /// assert!(from::<Max>(u64::MAX));
+    /// assert!(from::<Max>(u64::MAX as i128));
+    /// assert!(from::<Max>(u64::MAX as u128));
+    /// assert!(!from::<Max>(u64::MAX as u128 + 1));
+    /// assert!(!from::<Max>(-1i64));
+    ///
+    /// assert!(from::<ConstU64<0>>(0i64));
+    /// assert!(from::<ConstU64<0>>(0i128));
+    ///
+    /// assert!(from::<ConstU128<{ u128::MAX }>>(u128::MAX));
+    /// assert!(!from::<ConstU128<{ u128::MAX }>>(-1i128));
+    /// assert!(from::<ConstU128<{ i128::MAX as u128 }>>(i128::MAX));
+    /// ```
+    u64 => ConstU64 ConstU64Visitor serialize_u64 deserialize_u64,
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde::{de::{value, IntoDeserializer}, Deserialize};
+    /// use serde_constant::ConstU128;
+    /// let big = u64::MAX as u128 + 1;
+    /// let err = ConstU128::<2>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(big))
+    ///     .unwrap_err();
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid value: integer `18446744073709551616`, expected the constant u128 2",
+    /// );
+    /// let err = ConstU128::<2>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(i128::MIN))
+    ///     .unwrap_err();
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid value: integer `-170141183460469231731687303715884105728`, expected the constant u128 2",
+    /// );
+    /// ```
+    ///
+    /// Binary formats carry the full 128 bits:
+    ///
+    /// ```
+    /// use serde_constant::ConstU128;
+    /// type Max = ConstU128<{ u128::MAX }>;
+    ///
+    /// let bytes = bincode::serialize(&Max::default()).unwrap();
+    /// assert_eq!(bincode::deserialize::<Max>(&bytes).unwrap(), Max::default());
+    /// assert!(bincode::deserialize::<ConstU128<2>>(&bytes).is_err());
+    ///
+    /// let bytes = postcard::to_allocvec(&Max::default()).unwrap();
+    /// assert_eq!(postcard::from_bytes::<Max>(&bytes).unwrap(), Max::default());
+    /// assert!(postcard::from_bytes::<ConstU128<2>>(&bytes).is_err());
+    /// ```
+    ///
+    /// It can be ordered against a `u128`, across the full range.
+    ///
+    /// ```
+    /// use serde_constant::ConstU128;
+    /// assert!(ConstU128::<{ u128::MAX }> > u128::MAX - 1);
+    /// assert!(u128::MAX - 1 < ConstU128::<{ u128::MAX }>);
+    /// ```
+    ///
+    /// [`Debug`](fmt::Debug) includes the value.
+    ///
+    /// ```
+    /// use serde_constant::ConstU128;
+    /// assert_eq!(format!("{:?}", ConstU128::<{ u128::MAX }>), format!("ConstU128({})", u128::MAX));
+    /// ```
+    u128 => ConstU128 ConstU128Visitor serialize_u128 deserialize_u128,
+    ///
+    /// This is serialized as a `u64`. Values that don't fit in a `usize` on the current target are
+    /// rejected rather than wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// use serde_constant::ConstUsize;
+    /// assert!(serde_json::from_value::<ConstUsize<{ u32::MAX as usize }>>(json!(u32::MAX)).is_ok());
+    /// # #[cfg(target_pointer_width = "64")]
+    /// assert!(serde_json::from_value::<ConstUsize<{ u64::MAX as usize }>>(json!(u64::MAX)).is_ok());
+    /// // would be 5 if truncated to 32 bits
+    /// assert!(serde_json::from_value::<ConstUsize<5>>(json!((1u64 << 32) + 5)).is_err());
+    /// ```
+    ///
+    /// The value is available in const contexts, e.g. as an array length.
+    ///
+    /// ```
+    /// use serde_constant::ConstUsize;
+    /// let buf = [0u8; ConstUsize::<4>::VALUE];
+    /// assert_eq!(buf.len(), ConstUsize::<4>.get());
+    /// ```
+    usize => ConstUsize ConstUsizeVisitor serialize_u64 deserialize_u64,
+);
+
+macro_rules! impl_widen {
+    ($($type:ty => $struct:ident, $wide_type:ty => $wide_struct:ident;)*) => {
+        $(
+            impl<const V: $type> $struct<V> {
+                #[doc = concat!("Converts to the [`", stringify!($wide_struct), "`] with the same value.")]
+                ///
+                #[doc = concat!("`W` must be `V as ", stringify!($wide_type), "`, which is checked at compile time. It can usually be")]
+                /// inferred from the expected type.
+                pub const fn widen<const W: $wide_type>(self) -> $wide_struct<W> {
+                    const { assert!(W == V as $wide_type, "widened constant has a different value") };
+                    $wide_struct::<W>
+                }
+            }
+        )*
+    };
+}
+
+impl_widen!(
+    i8 => ConstI8, i16 => ConstI16;
+    i16 => ConstI16, i32 => ConstI32;
+    i32 => ConstI32, i64 => ConstI64;
+    i64 => ConstI64, i128 => ConstI128;
+    u8 => ConstU8, u16 => ConstU16;
+    u16 => ConstU16, u32 => ConstU32;
+    u32 => ConstU32, u64 => ConstU64;
+    u64 => ConstU64, u128 => ConstU128;
+);
+
+macro_rules! declare_extremes {
+    ($($(#[$attr:meta])* $alias:ident = $struct:ident<$type:ident::$bound:ident>;)*) => {
+        $(
+            #[doc = concat!("The [`", stringify!($struct), "`] of `", stringify!($type), "::", stringify!($bound), "`, for sentinel values.")]
+            $(#[$attr])*
+            pub type $alias = $struct<{ $type::$bound }>;
+        )*
+    };
+}
+
+declare_extremes!(
+    ConstI8Min = ConstI8<i8::MIN>;
+    ConstI8Max = ConstI8<i8::MAX>;
+    ConstI16Min = ConstI16<i16::MIN>;
+    ConstI16Max = ConstI16<i16::MAX>;
+    ConstI32Min = ConstI32<i32::MIN>;
+    ConstI32Max = ConstI32<i32::MAX>;
+    ConstI64Min = ConstI64<i64::MIN>;
+    ConstI64Max = ConstI64<i64::MAX>;
+    ConstI128Min = ConstI128<i128::MIN>;
+    ConstI128Max = ConstI128<i128::MAX>;
+    ConstIsizeMin = ConstIsize<isize::MIN>;
+    ConstIsizeMax = ConstIsize<isize::MAX>;
+    ///
+    /// The minimum of an unsigned type is just `0`, so there's no `ConstU8Min`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// use serde_constant::{ConstU64Max, ConstU8Max};
+    /// assert_eq!(ConstU8Max::default(), 255);
+    /// assert!(serde_json::from_value::<ConstU8Max>(json!(255)).is_ok());
+    /// # #[cfg(not(feature = "alloc"))]
+    /// assert_eq!(
+    ///     serde_json::from_value::<ConstU8Max>(json!(254)).unwrap_err().to_string(),
+    ///     "invalid value: integer `254`, expected the constant u8 255",
+    /// );
+    /// assert!(serde_json::from_value::<ConstU64Max>(json!(u64::MAX)).is_ok());
+    /// ```
+    ConstU8Max = ConstU8<u8::MAX>;
+    ConstU16Max = ConstU16<u16::MAX>;
+    ConstU32Max = ConstU32<u32::MAX>;
+    ConstU64Max = ConstU64<u64::MAX>;
+    ConstU128Max = ConstU128<u128::MAX>;
+    ConstUsizeMax = ConstUsize<usize::MAX>;
+);
+
+macro_rules! declare_nonzero {
+    ($($(#[$attr:meta])* $type:ty => $struct:ident($nonzero:ty) $inner:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("A const `", stringify!($nonzero), "`.")]
+            ///
+            /// `NonZero` types can't be used as const parameters, so `V` is the underlying primitive
+            /// instead. With a `V` of zero, the type can be named, but it's a compile error once the
+            /// constant is serialized, deserialized, formatted, converted, or compared to a value.
+            #[doc = concat!("Otherwise it behaves exactly like [`", stringify!($inner), "`].")]
+            ///
+            /// Deserialization fails if the value is not `V`, so zero is always rejected.
+            $(#[$attr])*
+            #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+            pub struct $struct<const V: $type>;
+
+            impl<const V: $type> $struct<V> {
+                /// The constant, for `const` items and struct literals.
+                pub const NEW: Self = $struct;
+
+                /// Returns the constant.
+                pub const fn new() -> Self {
+                    Self::NEW
+                }
+
+                #[doc = concat!("The underlying value, `V` as a `", stringify!($nonzero), "`.")]
+                pub const VALUE: $nonzero = match <$nonzero>::new(V) {
+                    Some(v) => v,
+                    None => panic!(concat!("`", stringify!($struct), "` cannot be zero")),
+                };
+
+                #[doc = concat!("Returns `V` as a `", stringify!($nonzero), "`.")]
+                pub const fn get(self) -> $nonzero {
+                    Self::VALUE
+                }
+            }
+
+            impl<const V: $type> ConstValue for $struct<V> {
+                type Repr = $nonzero;
+                const VALUE: $nonzero = Self::VALUE;
+            }
+
+            impl<const V: $type> From<$struct<V>> for $nonzero {
+                fn from(_: $struct<V>) -> $nonzero {
+                    $struct::<V>::VALUE
+                }
+            }
+
+            impl<const V: $type> From<&$struct<V>> for $nonzero {
+                fn from(_: &$struct<V>) -> $nonzero {
+                    $struct::<V>::VALUE
+                }
+            }
+
+            impl<const V: $type> TryFrom<$nonzero> for $struct<V> {
+                type Error = ConstMismatch<$nonzero>;
+                fn try_from(value: $nonzero) -> Result<Self, Self::Error> {
+                    if value == $struct::<V>::VALUE {
+                        Ok($struct::<V>)
+                    } else {
+                        Err(ConstMismatch {
+                            expected: $struct::<V>::VALUE,
+                            found: value,
+                        })
+                    }
+                }
+            }
+
+            impl<const V: $type> FromStr for $struct<V> {
+                type Err = ParseConstError<$nonzero>;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_const(s)
+                }
+            }
+
+            impl<const V: $type> PartialEq<$type> for $struct<V> {
+                fn eq(&self, other: &$type) -> bool {
+                    Self::VALUE.get() == *other
+                }
+            }
+
+            impl<const V: $type> PartialEq<$struct<V>> for $type {
+                fn eq(&self, _: &$struct<V>) -> bool {
+                    *self == $struct::<V>::VALUE.get()
+                }
+            }
+
+            impl<const V: $type> PartialEq<$nonzero> for $struct<V> {
+                fn eq(&self, other: &$nonzero) -> bool {
+                    Self::VALUE == *other
+                }
+            }
+
+            impl<const V: $type> PartialEq<$struct<V>> for $nonzero {
+                fn eq(&self, _: &$struct<V>) -> bool {
+                    *self == $struct::<V>::VALUE
+                }
+            }
+
+            impl<const V: $type> PartialOrd<$type> for $struct<V> {
+                fn partial_cmp(&self, other: &$type) -> Option<Ordering> {
+                    Some(Self::VALUE.get().cmp(other))
+                }
+            }
+
+            impl<const V: $type> PartialOrd<$struct<V>> for $type {
+                fn partial_cmp(&self, _: &$struct<V>) -> Option<Ordering> {
+                    Some(self.cmp(&$struct::<V>::VALUE.get()))
+                }
+            }
+
+            impl<const V: $type> PartialOrd<$nonzero> for $struct<V> {
+                fn partial_cmp(&self, other: &$nonzero) -> Option<Ordering> {
+                    Some(Self::VALUE.cmp(other))
+                }
+            }
+
+            impl<const V: $type> PartialOrd<$struct<V>> for $nonzero {
+                fn partial_cmp(&self, _: &$struct<V>) -> Option<Ordering> {
+                    Some(self.cmp(&$struct::<V>::VALUE))
+                }
+            }
+
+            impl<const V: $type> fmt::Debug for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($struct)).field(&Self::VALUE).finish()
+                }
+            }
+
+            impl<const V: $type> fmt::Display for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&Self::VALUE, f)
+                }
+            }
+
+            impl<const V: $type> fmt::LowerHex for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::LowerHex::fmt(&Self::VALUE, f)
+                }
+            }
+
+            impl<const V: $type> fmt::UpperHex for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::UpperHex::fmt(&Self::VALUE, f)
+                }
+            }
+
+            impl<const V: $type> fmt::Binary for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Binary::fmt(&Self::VALUE, f)
+                }
+            }
+
+            impl<const V: $type> fmt::Octal for $struct<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Octal::fmt(&Self::VALUE, f)
+                }
+            }
+
+            impl<const V: $type> Serialize for $struct<V> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let _ = Self::VALUE;
+                    $inner::<V>.serialize(serializer)
+                }
+            }
+
+            impl<'de, const V: $type> Deserialize<'de> for $struct<V> {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let _ = Self::VALUE;
+                    $inner::<V>::deserialize(deserializer).map(|_| $struct::<V>)
+                }
+            }
+
+            impl<'de, const V: $type> DeserializeAny<'de> for $struct<V> {
+                fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let _ = Self::VALUE;
+                    $inner::<V>::deserialize_any(deserializer).map(|_| $struct::<V>)
+                }
+            }
+
+            impl<'de, const V: $type, E: de::Error> IntoDeserializer<'de, E> for $struct<V> {
+                type Deserializer = <$type as IntoDeserializer<'de, E>>::Deserializer;
+                fn into_deserializer(self) -> Self::Deserializer {
+                    Self::VALUE.get().into_deserializer()
+                }
+            }
+        )*
+    };
+}
+
+declare_nonzero!(
+    i8 => ConstNonZeroI8(NonZeroI8) ConstI8,
+    i16 => ConstNonZeroI16(NonZeroI16) ConstI16,
+    i32 => ConstNonZeroI32(NonZeroI32) ConstI32,
+    i64 => ConstNonZeroI64(NonZeroI64) ConstI64,
+    i128 => ConstNonZeroI128(NonZeroI128) ConstI128,
+    isize => ConstNonZeroIsize(NonZeroIsize) ConstIsize,
+    u8 => ConstNonZeroU8(NonZeroU8) ConstU8,
+    u16 => ConstNonZeroU16(NonZeroU16) ConstU16,
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// use core::num::NonZeroU32;
+    /// use serde_constant::ConstNonZeroU32;
+    /// assert_eq!(ConstNonZeroU32::<7>.get().get(), 7);
+    /// assert_eq!(ConstNonZeroU32::<7>::VALUE.get(), 7);
+    /// assert!(serde_json::from_value::<ConstNonZeroU32<7>>(json!(7)).is_ok());
+    /// assert!(serde_json::from_value::<ConstNonZeroU32<7>>(json!(0)).is_err());
+    ///
+    /// assert_eq!(ConstNonZeroU32::<7>, 7);
+    /// assert_eq!(7, ConstNonZeroU32::<7>);
+    /// assert_eq!(ConstNonZeroU32::<7>, NonZeroU32::new(7).unwrap());
+    /// assert_ne!(NonZeroU32::MIN, ConstNonZeroU32::<7>);
+    ///
+    /// assert!(ConstNonZeroU32::<7> < 8);
+    /// assert!(8 > ConstNonZeroU32::<7>);
+    /// assert!(ConstNonZeroU32::<7> > NonZeroU32::MIN);
+    ///
+    /// assert_eq!(format!("{:?}", ConstNonZeroU32::<7>), "ConstNonZeroU32(7)");
+    /// ```
+    u32 => ConstNonZeroU32(NonZeroU32) ConstU32,
+    u64 => ConstNonZeroU64(NonZeroU64) ConstU64,
+    u128 => ConstNonZeroU128(NonZeroU128) ConstU128,
+    usize => ConstNonZeroUsize(NonZeroUsize) ConstUsize,
+);
+
+/// A const `char`.
+///
+/// In human-readable formats, this is serialized as a char. In compact formats, it's serialized as
+/// its codepoint, a `u32`, which is what deserialization expects from them in turn.
+///
+/// Deserialization fails if the value is not `V`. Besides chars, this accepts strings and UTF-8
+/// byte strings holding a single char, for formats that deliver chars that way.
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::ConstChar;
+/// use serde_test::{assert_tokens, Configure, Token};
+/// assert_tokens(&ConstChar::<'A'>.readable(), &[Token::Char('A')]);
+/// assert_tokens(&ConstChar::<'A'>.compact(), &[Token::U32(65)]);
+///
+/// let bytes = bincode::serialize(&ConstChar::<'A'>).unwrap();
+/// assert_eq!(bytes, 65u32.to_le_bytes());
+/// assert_eq!(bincode::deserialize::<ConstChar<'A'>>(&bytes).unwrap(), ConstChar::<'A'>);
+/// assert!(bincode::deserialize::<ConstChar<'B'>>(&bytes).is_err());
+/// ```
+///
+/// The codepoint is accepted through any integer `visit_*` method, and checked with
+/// [`char::from_u32`], so chars in and beyond the Basic Multilingual Plane round-trip through
+/// compact formats:
+///
+/// ```
+/// use serde::{de::DeserializeOwned, Serialize};
+/// use serde_constant::ConstChar;
+/// fn roundtrip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug + Default>() {
+///     let bytes = bincode::serialize(&T::default()).unwrap();
+///     assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), T::default());
+///     let bytes = postcard::to_allocvec(&T::default()).unwrap();
+///     assert_eq!(postcard::from_bytes::<T>(&bytes).unwrap(), T::default());
+/// }
+/// roundtrip::<ConstChar<'é'>>();
+/// roundtrip::<ConstChar<'\u{FFFD}'>>();
+/// roundtrip::<ConstChar<'🦀'>>();
+/// roundtrip::<ConstChar<'\u{10FFFF}'>>();
+///
+/// let bytes = postcard::to_allocvec(&ConstChar::<'🦀'>).unwrap();
+/// assert!(postcard::from_bytes::<ConstChar<'é'>>(&bytes).is_err());
+/// // a surrogate isn't a char
+/// let bytes = postcard::to_allocvec(&0xD83Eu32).unwrap();
+/// assert!(postcard::from_bytes::<ConstChar<'🦀'>>(&bytes).is_err());
+/// ```
+///
+/// Chars outside the Basic Multilingual Plane are a single char, despite being four bytes of UTF-8
+/// and two UTF-16 code units:
+///
+/// ```
+/// use serde_constant::ConstChar;
+/// let json = serde_json::to_string(&ConstChar::<'🦀'>).unwrap();
+/// assert_eq!(json, r#""🦀""#);
+/// assert!(serde_json::from_str::<ConstChar<'🦀'>>(&json).is_ok());
+/// assert!(serde_json::from_str::<ConstChar<'🦀'>>(r#""\ud83e\udd80""#).is_ok());
+/// assert!(serde_json::from_str::<ConstChar<'🦀'>>(r#""🦀🦀""#).is_err());
+/// assert!(serde_json::from_str::<ConstChar<'🦀'>>(r#""\ud83e""#).is_err());
+/// ```
+///
+/// ```
+/// use serde_constant::ConstChar;
+/// use serde_test::{assert_de_tokens, assert_de_tokens_error, Configure, Readable, Token};
+/// let c = ConstChar::<'é'>.readable();
+/// assert_de_tokens(&c, &[Token::Char('é')]);
+/// assert_de_tokens(&c, &[Token::String("é")]);
+/// assert_de_tokens(&c, &[Token::Bytes("é".as_bytes())]);
+/// assert_de_tokens(&c, &[Token::ByteBuf("é".as_bytes())]);
+/// assert_de_tokens_error::<Readable<ConstChar<'é'>>>(
+///     &[Token::Bytes(&[0xc3])],
+///     "invalid value: byte array, expected the constant char 'é'",
+/// );
+///
+/// assert_de_tokens_error::<Readable<ConstChar<'a'>>>(
+///     &[Token::Str("")],
+///     "invalid length 0, expected the constant char 'a'",
+/// );
+/// assert_de_tokens_error::<Readable<ConstChar<'a'>>>(
+///     &[Token::Str("ab")],
+///     r#"invalid value: string "ab", expected the constant char 'a'"#,
+/// );
+/// assert_de_tokens_error::<Readable<ConstChar<'a'>>>(
+///     &[Token::Str("abcde")],
+///     r#"invalid value: string "abcde", expected the constant char 'a'"#,
+/// );
+/// ```
+///
+/// A char followed by combining marks looks like a single char, but is several, so it's rejected
+/// with a message saying so.
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstChar;
+/// let decomposed = "e\u{301}";
+/// assert_eq!(decomposed.chars().count(), 2);
+/// assert_eq!(
+///     serde_json::from_value::<ConstChar<'é'>>(json!(decomposed)).unwrap_err().to_string(),
+///     "invalid value: grapheme cluster of 2 code points, expected the constant char 'é'",
+/// );
+/// assert_eq!(
+///     serde_json::from_value::<ConstChar<'e'>>(json!("e\u{301}\u{323}")).unwrap_err().to_string(),
+///     "invalid value: grapheme cluster of 3 code points, expected the constant char 'e'",
+/// );
+/// assert!(serde_json::from_value::<ConstChar<'é'>>(json!("\u{e9}")).is_ok());
+/// ```
+///
+/// With the `trim-strings` feature, ASCII whitespace around a string is ignored.
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstChar;
+/// let padded = serde_json::from_value::<ConstChar<'Y'>>(json!(" Y\t"));
+/// # #[cfg(feature = "trim-strings")]
+/// assert!(padded.is_ok());
+/// # #[cfg(not(feature = "trim-strings"))]
+/// assert_eq!(
+///     padded.unwrap_err().to_string(),
+///     "invalid value: string \" Y\\t\", expected the constant char 'Y'",
+/// );
+///
+/// assert!(serde_json::from_value::<ConstChar<'Y'>>(json!(" Y N ")).is_err());
+/// assert!(serde_json::from_value::<ConstChar<' '>>(json!(" ")).is_ok());
+/// ```
+///
+/// With the `char-codepoints` feature, human-readable formats can deliver the codepoint as well.
+///
+/// ```
+/// # #[cfg(feature = "char-codepoints")] {
+/// # use serde_json::json;
+/// use serde_constant::ConstChar;
+/// assert!(serde_json::from_value::<ConstChar<'A'>>(json!(65)).is_ok());
+/// assert!(serde_json::from_value::<ConstChar<'A'>>(json!(66)).is_err());
+/// assert_eq!(
+///     serde_json::from_value::<ConstChar<'A'>>(json!(0xD800)).unwrap_err().to_string(),
+///     "invalid value: integer `55296`, expected the constant char 'A'",
+/// );
+/// assert!(serde_json::from_value::<ConstChar<'A'>>(json!(-65)).is_err());
+/// # }
+/// ```
+///
+/// It can be compared directly with a `char`.
+///
+/// ```
+/// use serde_constant::ConstChar;
+/// assert!(ConstChar::<'x'> == 'x');
+/// assert!('x' == ConstChar::<'x'>);
+/// ```
+///
+/// It can be ordered against a `char` too.
+///
+/// ```
+/// use serde_constant::ConstChar;
+/// assert!(ConstChar::<'b'> > 'a');
+/// assert!('z' > ConstChar::<'b'>);
+/// ```
+///
+/// [`Display`](fmt::Display) writes the char quoted, the way it appears in errors.
+///
+/// ```
+/// use serde_constant::ConstChar;
+/// assert_eq!(format!("{}", ConstChar::<'c'>), "'c'");
+/// ```
+///
+/// [`Debug`](fmt::Debug) includes the char too.
+///
+/// ```
+/// use serde_constant::ConstChar;
+/// assert_eq!(format!("{:?}", ConstChar::<'x'>), "ConstChar('x')");
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstChar<const V: char>;
+
+impl<const V: char> ConstChar<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstChar;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+
+    /// The underlying value.
+    pub const VALUE: char = V;
+
+    /// Returns the underlying value.
+    pub const fn get(self) -> char {
+        Self::VALUE
+    }
+}
+
+impl<const V: char> ConstValue for ConstChar<V> {
+    type Repr = char;
+    const VALUE: char = V;
+}
+
+/// ```
+/// use serde_constant::ConstChar;
+/// let c: char = ConstChar::<'x'>.into();
+/// assert_eq!(c, 'x');
+/// ```
+impl<const V: char> From<ConstChar<V>> for char {
+    fn from(_: ConstChar<V>) -> char {
+        V
+    }
+}
+
+impl<const V: char> From<&ConstChar<V>> for char {
+    fn from(_: &ConstChar<V>) -> char {
+        V
+    }
+}
+
+impl<const V: char> TryFrom<char> for ConstChar<V> {
+    type Error = ConstMismatch<char>;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if value == V {
+            Ok(ConstChar::<V>)
+        } else {
+            Err(ConstMismatch {
+                expected: V,
+                found: value,
+            })
+        }
+    }
+}
+
+impl<const V: char> FromStr for ConstChar<V> {
+    type Err = ParseConstError<char>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_const(s)
+    }
+}
+
+impl<const V: char> PartialEq<char> for ConstChar<V> {
+    fn eq(&self, other: &char) -> bool {
+        V == *other
+    }
+}
+
+impl<const V: char> PartialEq<ConstChar<V>> for char {
+    fn eq(&self, _: &ConstChar<V>) -> bool {
+        *self == V
+    }
+}
+
+impl<const V: char> PartialOrd<char> for ConstChar<V> {
+    fn partial_cmp(&self, other: &char) -> Option<Ordering> {
+        Some(V.cmp(other))
+    }
+}
+
+impl<const V: char> PartialOrd<ConstChar<V>> for char {
+    fn partial_cmp(&self, _: &ConstChar<V>) -> Option<Ordering> {
+        Some(self.cmp(&V))
+    }
+}
+
+impl<const V: char> fmt::Debug for ConstChar<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstChar").field(&V).finish()
+    }
+}
+
+impl<const V: char> fmt::Display for ConstChar<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{V}'")
+    }
+}
+
+impl<const V: char> Serialize for ConstChar<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_char(V)
+        } else {
+            serializer.serialize_u32(V as u32)
+        }
+    }
+}
+
+impl<'de, const V: char> Deserialize<'de> for ConstChar<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_u32(ConstCharVisitor::<V>);
+        }
+        // hinted deserialize calls never hand integers to the visitor
+        #[cfg(feature = "char-codepoints")]
+        return deserializer.deserialize_any(ConstCharVisitor::<V>);
+        #[cfg(not(feature = "char-codepoints"))]
+        deserializer.deserialize_char(ConstCharVisitor::<V>)
+    }
+}
+
+impl<'de, const V: char> DeserializeAny<'de> for ConstChar<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstCharVisitor::<V>)
+    }
+}
+
+impl<'de, const V: char, E: de::Error> IntoDeserializer<'de, E> for ConstChar<V> {
+    type Deserializer = <char as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        V.into_deserializer()
+    }
+}
+
+struct ConstCharVisitor<const V: char>;
+
+impl<'de, const V: char> Visitor<'de> for ConstCharVisitor<V> {
+    type Value = ConstChar<V>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant char {}", ConstChar::<V>)
+    }
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v == V {
+            Ok(ConstChar::<V>)
+        } else {
+            Err(E::invalid_value(Unexpected::Char(v), &self))
+        }
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // a whitespace constant still matches itself untrimmed
+        #[cfg(feature = "trim-strings")]
+        let trimmed = match v.trim_ascii() {
+            "" => v,
+            trimmed => trimmed,
+        };
+        #[cfg(not(feature = "trim-strings"))]
+        let trimmed = v;
+        let mut chs = trimmed.chars();
+        let ch = chs.next().ok_or_else(|| E::invalid_length(0, &self))?;
+        if chs.next().is_some() {
+            Err(invalid_char_str(v, trimmed, &self))
+        } else {
+            self.visit_char(ch)
+        }
+    }
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(v)
+    }
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // surrogates and values past U+10FFFF aren't chars
+        match u32::try_from(v).ok().and_then(char::from_u32) {
+            Some(ch) if ch == V => Ok(ConstChar::<V>),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+}
+
+/// A [`ConstChar`] that is deserialized with [`Deserializer::deserialize_any`].
+///
+/// `ConstChar` asks compact formats for the codepoint as a `u32`. Self-describing compact formats
+/// like MessagePack may have the char written as a string instead, and some of them fail rather
+/// than hand a string to a `u32` hint. This lets the format produce the value however it was
+/// written, as a char, a string, or a codepoint. Like any [`ConstAny`], it only works with formats
+/// that support `deserialize_any`, and it serializes exactly like `ConstChar`.
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::{ConstChar, ConstCharAny};
+/// // rmp-serde writes chars as strings
+/// let bytes = rmp_serde::to_vec(&'x').unwrap();
+/// assert!(rmp_serde::from_slice::<ConstChar<'x'>>(&bytes).is_err());
+/// assert!(rmp_serde::from_slice::<ConstCharAny<'x'>>(&bytes).is_ok());
+/// assert!(rmp_serde::from_slice::<ConstCharAny<'y'>>(&bytes).is_err());
+///
+/// // and the codepoint that `ConstChar` writes is still accepted
+/// let bytes = rmp_serde::to_vec(&ConstChar::<'x'>).unwrap();
+/// assert!(rmp_serde::from_slice::<ConstCharAny<'x'>>(&bytes).is_ok());
+/// ```
+pub type ConstCharAny<const V: char> = ConstAny<ConstChar<V>>;
+
+/// A const `char`, compared case-insensitively.
+///
+/// Deserialization fails if the value is not `V` after lowercasing both. This uses the full
+/// Unicode lowercase mapping from [`char::to_lowercase`], so it's not limited to ASCII.
+/// Serialization always writes `V` as given.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstCharCi;
+/// assert!(serde_json::from_value::<ConstCharCi<'Y'>>(json!("Y")).is_ok());
+/// assert!(serde_json::from_value::<ConstCharCi<'Y'>>(json!("y")).is_ok());
+/// assert_eq!(
+///     serde_json::from_value::<ConstCharCi<'Y'>>(json!("z")).unwrap_err().to_string(),
+///     "invalid value: character `z`, expected the constant char 'Y' (case-insensitive)",
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstCharCi<const V: char>;
+
+impl<const V: char> ConstCharCi<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstCharCi;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+
+    /// The underlying value.
+    pub const VALUE: char = V;
+
+    /// Returns the underlying value.
+    pub const fn get(self) -> char {
+        Self::VALUE
+    }
+}
+
+/// Lists the characters that match `V`, starting with `V` itself, for schema formats that
+/// can't express case-insensitive matching.
+///
+/// These are `V` and its single-character lowercase and uppercase mappings. Characters that only
+/// lowercase to the same thing, like the Kelvin sign for `'k'`, aren't listed.
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+impl<const V: char> ConstCharCi<V> {
+    fn spellings() -> alloc::vec::Vec<char> {
+        let mut spellings = alloc::vec![V];
+        for ch in V.to_lowercase().chain(V.to_uppercase()) {
+            if eq_ignore_case(ch, V) && !spellings.contains(&ch) {
+                spellings.push(ch);
+            }
+        }
+        spellings
+    }
+}
+
+impl<const V: char> ConstValue for ConstCharCi<V> {
+    type Repr = char;
+    const VALUE: char = V;
+}
+
+impl<const V: char> From<ConstCharCi<V>> for char {
+    fn from(_: ConstCharCi<V>) -> char {
+        V
+    }
+}
+
+impl<const V: char> From<&ConstCharCi<V>> for char {
+    fn from(_: &ConstCharCi<V>) -> char {
+        V
+    }
+}
+
+impl<const V: char> TryFrom<char> for ConstCharCi<V> {
+    type Error = ConstMismatch<char>;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if eq_ignore_case(value, V) {
+            Ok(ConstCharCi::<V>)
+        } else {
+            Err(ConstMismatch {
+                expected: V,
+                found: value,
+            })
+        }
+    }
+}
+
+impl<const V: char> FromStr for ConstCharCi<V> {
+    type Err = ParseConstError<char>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_const(s)
+    }
+}
+
+impl<const V: char> fmt::Debug for ConstCharCi<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstCharCi").field(&V).finish()
+    }
+}
+
+impl<const V: char> fmt::Display for ConstCharCi<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{V}'")
+    }
+}
+
+impl<const V: char> Serialize for ConstCharCi<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_char(V)
+    }
+}
+
+impl<'de, const V: char> Deserialize<'de> for ConstCharCi<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_char(ConstCharCiVisitor::<V>)
+    }
+}
+
+impl<'de, const V: char> DeserializeAny<'de> for ConstCharCi<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstCharCiVisitor::<V>)
+    }
+}
+
+impl<'de, const V: char, E: de::Error> IntoDeserializer<'de, E> for ConstCharCi<V> {
+    type Deserializer = <char as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        V.into_deserializer()
+    }
+}
+
+/// Compares the way [`ConstCharCi`] does, by the full Unicode lowercase mapping.
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a.to_lowercase().eq(b.to_lowercase())
+}
+
+struct ConstCharCiVisitor<const V: char>;
+
+impl<'de, const V: char> Visitor<'de> for ConstCharCiVisitor<V> {
+    type Value = ConstCharCi<V>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "the constant char {} (case-insensitive)",
+            ConstCharCi::<V>
+        )
+    }
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if eq_ignore_case(v, V) {
+            Ok(ConstCharCi::<V>)
+        } else {
+            Err(E::invalid_value(Unexpected::Char(v), &self))
+        }
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let mut chs = v.chars();
+        let ch = chs.next().ok_or_else(|| E::invalid_length(0, &self))?;
+        if chs.next().is_some() {
+            Err(invalid_char_str(v, v, &self))
+        } else {
+            self.visit_char(ch)
+        }
+    }
+}
+
+/// A const `char` that must be printable, i.e. not a control character.
+///
+/// This (de)serializes exactly like [`ConstChar`], but using a control character such as `'\n'`
+/// for `V` is a compile error. The check runs when the type is used, e.g. serialized,
+/// deserialized, or converted, rather than when it's named, so a `ConstPrintableChar<'\n'>` that
+/// is never used doesn't trigger it.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstPrintableChar;
+/// assert_eq!(serde_json::to_value(ConstPrintableChar::<'#'>).unwrap(), json!("#"));
+/// assert!(serde_json::from_value::<ConstPrintableChar<'#'>>(json!("#")).is_ok());
+/// assert!(serde_json::from_value::<ConstPrintableChar<'#'>>(json!("!")).is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstPrintableChar<const V: char>;
+
+impl<const V: char> ConstPrintableChar<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstPrintableChar;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+
+    /// The underlying value.
+    pub const VALUE: char = {
+        // the same range as `char::is_control`, which isn't const
+        assert!(
+            !matches!(V, '\0'..='\x1f' | '\x7f'..='\u{9f}'),
+            "ConstPrintableChar can't be a control character"
+        );
+        V
+    };
+
+    /// Returns the underlying value.
+    pub const fn get(self) -> char {
+        Self::VALUE
+    }
+
+    /// Returns the equivalent [`ConstChar`], checking that `V` is printable.
+    const fn checked() -> ConstChar<V> {
+        let _ = Self::VALUE;
+        ConstChar::<V>
+    }
+}
+
+impl<const V: char> ConstValue for ConstPrintableChar<V> {
+    type Repr = char;
+    const VALUE: char = ConstPrintableChar::<V>::VALUE;
+}
+
+impl<const V: char> From<ConstPrintableChar<V>> for char {
+    fn from(_: ConstPrintableChar<V>) -> char {
+        ConstPrintableChar::<V>::VALUE
+    }
+}
+
+impl<const V: char> From<&ConstPrintableChar<V>> for char {
+    fn from(_: &ConstPrintableChar<V>) -> char {
+        ConstPrintableChar::<V>::VALUE
+    }
+}
+
+impl<const V: char> From<ConstPrintableChar<V>> for ConstChar<V> {
+    fn from(_: ConstPrintableChar<V>) -> Self {
+        ConstPrintableChar::<V>::checked()
+    }
+}
+
+impl<const V: char> TryFrom<char> for ConstPrintableChar<V> {
+    type Error = ConstMismatch<char>;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        ConstChar::<V>::try_from(value).map(ConstPrintableChar::<V>::from)
+    }
+}
+
+impl<const V: char> From<ConstChar<V>> for ConstPrintableChar<V> {
+    fn from(_: ConstChar<V>) -> Self {
+        let _ = Self::VALUE;
+        ConstPrintableChar::<V>
+    }
+}
+
+impl<const V: char> FromStr for ConstPrintableChar<V> {
+    type Err = ParseConstError<char>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_const(s)
+    }
+}
+
+impl<const V: char> fmt::Debug for ConstPrintableChar<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstPrintableChar")
+            .field(&Self::VALUE)
+            .finish()
+    }
+}
+
+impl<const V: char> fmt::Display for ConstPrintableChar<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Self::checked(), f)
+    }
+}
+
+impl<const V: char> Serialize for ConstPrintableChar<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::checked().serialize(serializer)
+    }
+}
+
+impl<'de, const V: char> Deserialize<'de> for ConstPrintableChar<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ConstChar::<V>::deserialize(deserializer).map(ConstPrintableChar::<V>::from)
+    }
+}
+
+impl<'de, const V: char> DeserializeAny<'de> for ConstPrintableChar<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <ConstChar<V> as DeserializeAny>::deserialize_any(deserializer)
+            .map(ConstPrintableChar::<V>::from)
+    }
+}
+
+impl<'de, const V: char, E: de::Error> IntoDeserializer<'de, E> for ConstPrintableChar<V> {
+    type Deserializer = <char as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        Self::VALUE.into_deserializer()
+    }
+}
+
+/// A const `u32` that must be a Unicode scalar value, i.e. a valid `char`.
+///
+/// This (de)serializes exactly like [`ConstU32`], for formats that store codepoints as integers,
+/// but using a surrogate or a value past `U+10FFFF` for `V` is a compile error. Like
+/// [`ConstPrintableChar`], the check runs when the type is used rather than when it's named.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstScalarU32;
+/// type Euro = ConstScalarU32<0x20AC>;
+/// assert_eq!(Euro::CHAR, '€');
+/// assert_eq!(serde_json::to_value(Euro::default()).unwrap(), json!(0x20AC));
+/// assert!(serde_json::from_value::<Euro>(json!(0x20AC)).is_ok());
+/// assert!(serde_json::from_value::<Euro>(json!(0x20AD)).is_err());
+/// assert!(serde_json::from_value::<Euro>(json!("€")).is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstScalarU32<const V: u32>;
+
+impl<const V: u32> ConstScalarU32<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstScalarU32;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+
+    /// The underlying value.
+    pub const VALUE: u32 = {
+        let _ = Self::CHAR;
+        V
+    };
+
+    /// The `char` with the underlying value as its codepoint.
+    pub const CHAR: char = match char::from_u32(V) {
+        Some(ch) => ch,
+        None => panic!("ConstScalarU32 has to be a Unicode scalar value"),
+    };
+
+    /// Returns the underlying value.
+    pub const fn get(self) -> u32 {
+        Self::VALUE
+    }
+
+    /// Returns the equivalent [`ConstU32`], checking that `V` is a scalar value.
+    const fn checked() -> ConstU32<V> {
+        let _ = Self::VALUE;
+        ConstU32::<V>
+    }
+}
+
+impl<const V: u32> ConstValue for ConstScalarU32<V> {
+    type Repr = u32;
+    const VALUE: u32 = ConstScalarU32::<V>::VALUE;
+}
+
+impl<const V: u32> From<ConstScalarU32<V>> for u32 {
+    fn from(_: ConstScalarU32<V>) -> u32 {
+        ConstScalarU32::<V>::VALUE
+    }
+}
+
+impl<const V: u32> From<&ConstScalarU32<V>> for u32 {
+    fn from(_: &ConstScalarU32<V>) -> u32 {
+        ConstScalarU32::<V>::VALUE
+    }
+}
+
+impl<const V: u32> From<ConstScalarU32<V>> for ConstU32<V> {
+    fn from(_: ConstScalarU32<V>) -> Self {
+        ConstScalarU32::<V>::checked()
+    }
+}
+
+impl<const V: u32> TryFrom<u32> for ConstScalarU32<V> {
+    type Error = ConstMismatch<u32>;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        ConstU32::<V>::try_from(value).map(ConstScalarU32::<V>::from)
+    }
+}
+
+impl<const V: u32> From<ConstU32<V>> for ConstScalarU32<V> {
+    fn from(_: ConstU32<V>) -> Self {
+        let _ = Self::VALUE;
+        ConstScalarU32::<V>
+    }
+}
+
+impl<const V: u32> FromStr for ConstScalarU32<V> {
+    type Err = ParseConstError<u32>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_const(s)
+    }
+}
+
+impl<const V: u32> fmt::Debug for ConstScalarU32<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstScalarU32").field(&Self::VALUE).finish()
+    }
+}
+
+impl<const V: u32> fmt::Display for ConstScalarU32<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Self::checked(), f)
+    }
+}
+
+impl<const V: u32> Serialize for ConstScalarU32<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::checked().serialize(serializer)
+    }
+}
+
+impl<'de, const V: u32> Deserialize<'de> for ConstScalarU32<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ConstU32::<V>::deserialize(deserializer).map(ConstScalarU32::<V>::from)
+    }
+}
+
+impl<'de, const V: u32> DeserializeAny<'de> for ConstScalarU32<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <ConstU32<V> as DeserializeAny>::deserialize_any(deserializer)
+            .map(ConstScalarU32::<V>::from)
+    }
+}
+
+impl<'de, const V: u32, E: de::Error> IntoDeserializer<'de, E> for ConstScalarU32<V> {
+    type Deserializer = <u32 as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        Self::VALUE.into_deserializer()
+    }
+}
+
+/// A const `u64` that JavaScript numbers can hold exactly, i.e. at most 2<sup>53</sup>.
+///
+/// This (de)serializes exactly like [`ConstU64`], but using a larger value for `V` is a compile
+/// error, so that JSON consumers that read every number as an `f64` don't round it. Like
+/// [`ConstScalarU32`], the check runs when the type is used rather than when it's named.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstSafeU64;
+/// type Id = ConstSafeU64<{ 1 << 53 }>;
+/// assert_eq!(Id::VALUE as f64 as u64, Id::VALUE);
+/// assert_eq!(serde_json::to_value(Id::default()).unwrap(), json!(9007199254740992u64));
+/// assert!(serde_json::from_value::<Id>(json!(9007199254740992u64)).is_ok());
+/// assert!(serde_json::from_value::<Id>(json!(9007199254740993u64)).is_err());
+/// assert!(serde_json::from_value::<ConstSafeU64<7>>(json!(7)).is_ok());
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstSafeU64<const V: u64>;
+
+impl<const V: u64> ConstSafeU64<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstSafeU64;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+
+    /// The largest value allowed for `V`.
+    pub const MAX: u64 = 1 << 53;
+
+    /// The underlying value.
+    pub const VALUE: u64 = {
+        assert!(
+            V <= Self::MAX,
+            "ConstSafeU64 has to be at most 2^53, so that JavaScript can represent it exactly"
+        );
+        V
+    };
+
+    /// Returns the underlying value.
+    pub const fn get(self) -> u64 {
+        Self::VALUE
+    }
+
+    /// Returns the equivalent [`ConstU64`], checking that `V` is in range.
+    const fn checked() -> ConstU64<V> {
+        let _ = Self::VALUE;
+        ConstU64::<V>
+    }
+}
+
+impl<const V: u64> ConstValue for ConstSafeU64<V> {
+    type Repr = u64;
+    const VALUE: u64 = ConstSafeU64::<V>::VALUE;
+}
+
+impl<const V: u64> From<ConstSafeU64<V>> for u64 {
+    fn from(_: ConstSafeU64<V>) -> u64 {
+        ConstSafeU64::<V>::VALUE
+    }
+}
+
+impl<const V: u64> From<&ConstSafeU64<V>> for u64 {
+    fn from(_: &ConstSafeU64<V>) -> u64 {
+        ConstSafeU64::<V>::VALUE
+    }
+}
+
+impl<const V: u64> From<ConstSafeU64<V>> for ConstU64<V> {
+    fn from(_: ConstSafeU64<V>) -> Self {
+        ConstSafeU64::<V>::checked()
+    }
+}
+
+impl<const V: u64> TryFrom<u64> for ConstSafeU64<V> {
+    type Error = ConstMismatch<u64>;
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        ConstU64::<V>::try_from(value).map(ConstSafeU64::<V>::from)
+    }
+}
+
+impl<const V: u64> From<ConstU64<V>> for ConstSafeU64<V> {
+    fn from(_: ConstU64<V>) -> Self {
+        let _ = Self::VALUE;
+        ConstSafeU64::<V>
+    }
+}
+
+impl<const V: u64> FromStr for ConstSafeU64<V> {
+    type Err = ParseConstError<u64>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_const(s)
+    }
+}
+
+impl<const V: u64> fmt::Debug for ConstSafeU64<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstSafeU64").field(&Self::VALUE).finish()
+    }
+}
+
+impl<const V: u64> fmt::Display for ConstSafeU64<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Self::checked(), f)
+    }
+}
+
+impl<const V: u64> Serialize for ConstSafeU64<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::checked().serialize(serializer)
+    }
+}
+
+impl<'de, const V: u64> Deserialize<'de> for ConstSafeU64<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ConstU64::<V>::deserialize(deserializer).map(ConstSafeU64::<V>::from)
+    }
+}
+
+impl<'de, const V: u64> DeserializeAny<'de> for ConstSafeU64<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <ConstU64<V> as DeserializeAny>::deserialize_any(deserializer).map(ConstSafeU64::<V>::from)
+    }
+}
+
+impl<'de, const V: u64, E: de::Error> IntoDeserializer<'de, E> for ConstSafeU64<V> {
+    type Deserializer = <u64 as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        Self::VALUE.into_deserializer()
+    }
+}
+
+/// A `char` in the inclusive range `LO..=HI`.
+///
+/// Deserialization fails if the value is not in the range. Like `ConstOneOfI64`, this stores
+/// which char it matched, so that it serializes back to the same char, and it's a validation type
+/// rather than a single constant. The [`Default`] value is `LO`. Chars are serialized like
+/// [`ConstChar`], as their codepoint in formats that aren't human-readable.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstCharRange;
+/// type Letter = ConstCharRange<'A', 'Z'>;
+///
+/// let letter: Letter = serde_json::from_value(json!("Q")).unwrap();
+/// assert_eq!(letter.get(), 'Q');
+/// assert_eq!(serde_json::to_value(letter).unwrap(), json!("Q"));
+/// assert_eq!(Letter::default().get(), 'A');
+///
+/// // both ends are included
+/// assert!(serde_json::from_value::<Letter>(json!("A")).is_ok());
+/// assert!(serde_json::from_value::<Letter>(json!("Z")).is_ok());
+/// assert_eq!(
+///     serde_json::from_value::<Letter>(json!("a")).unwrap_err().to_string(),
+///     "invalid value: character `a`, expected a char in 'A'..='Z'",
+/// );
+/// assert!(serde_json::from_value::<Letter>(json!("@")).is_err());
+/// assert!(serde_json::from_value::<Letter>(json!("[")).is_err());
+///
+/// assert_eq!(Letter::new('B').map(Letter::get), Some('B'));
+/// assert_eq!(Letter::new('b'), None);
+///
+/// let bytes = postcard::to_allocvec(&letter).unwrap();
+/// assert_eq!(postcard::from_bytes::<Letter>(&bytes).unwrap(), letter);
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct ConstCharRange<const LO: char, const HI: char> {
+    value: char,
+}
+
+impl<const LO: char, const HI: char> ConstCharRange<LO, HI> {
+    /// Returns `value` as a constant, or `None` if it's not in `LO..=HI`.
+    pub const fn new(value: char) -> Option<Self> {
+        if LO <= value && value <= HI {
+            Some(ConstCharRange { value })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the matched char.
+    pub const fn get(self) -> char {
+        self.value
+    }
+}
+
+impl<const LO: char, const HI: char> Default for ConstCharRange<LO, HI> {
+    fn default() -> Self {
+        ConstCharRange { value: LO }
+    }
+}
+
+impl<const LO: char, const HI: char> From<ConstCharRange<LO, HI>> for char {
+    fn from(value: ConstCharRange<LO, HI>) -> char {
+        value.value
+    }
+}
+
+impl<const LO: char, const HI: char> From<&ConstCharRange<LO, HI>> for char {
+    fn from(value: &ConstCharRange<LO, HI>) -> char {
+        value.value
+    }
+}
+
+impl<const LO: char, const HI: char> fmt::Debug for ConstCharRange<LO, HI> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstCharRange").field(&self.value).finish()
+    }
+}
+
+impl<const LO: char, const HI: char> fmt::Display for ConstCharRange<LO, HI> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.value)
+    }
+}
+
+impl<const LO: char, const HI: char> Serialize for ConstCharRange<LO, HI> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_char(self.value)
+        } else {
+            serializer.serialize_u32(self.value as u32)
+        }
+    }
+}
+
+impl<'de, const LO: char, const HI: char> Deserialize<'de> for ConstCharRange<LO, HI> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_char(ConstCharRangeVisitor::<LO, HI>)
+        } else {
+            deserializer.deserialize_u32(ConstCharRangeVisitor::<LO, HI>)
+        }
+    }
+}
+
+impl<'de, const LO: char, const HI: char> DeserializeAny<'de> for ConstCharRange<LO, HI> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstCharRangeVisitor::<LO, HI>)
+    }
+}
+
+struct ConstCharRangeVisitor<const LO: char, const HI: char>;
+
+impl<'de, const LO: char, const HI: char> Visitor<'de> for ConstCharRangeVisitor<LO, HI> {
+    type Value = ConstCharRange<LO, HI>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a char in '{LO}'..='{HI}'")
+    }
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        ConstCharRange::new(v).ok_or_else(|| E::invalid_value(Unexpected::Char(v), &self))
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let mut chs = v.chars();
+        let ch = chs.next().ok_or_else(|| E::invalid_length(0, &self))?;
+        if chs.next().is_some() {
+            Err(invalid_char_str(v, v, &self))
+        } else {
+            self.visit_char(ch)
+        }
+    }
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match u32::try_from(v).ok().and_then(char::from_u32) {
+            Some(ch) => self.visit_char(ch),
+            None => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+}
+
+/// A const `()`, i.e. a field that is always `null`.
+///
+/// Deserialization fails if the value is not a unit or none value.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstUnit;
+/// assert_eq!(serde_json::to_value(ConstUnit).unwrap(), json!(null));
+/// assert!(serde_json::from_value::<ConstUnit>(json!(null)).is_ok());
+/// assert!(serde_json::from_value::<ConstUnit>(json!(0)).is_err());
+/// assert_eq!(format!("{}", ConstUnit), "()");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+pub struct ConstUnit;
+
+impl ConstUnit {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstUnit;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+impl ConstValue for ConstUnit {
+    type Repr = ();
+    const VALUE: () = ();
+}
+
+impl fmt::Display for ConstUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "()")
+    }
+}
+
+impl Serialize for ConstUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de> Deserialize<'de> for ConstUnit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_unit(ConstUnitVisitor)
+    }
+}
+
+impl<'de> DeserializeAny<'de> for ConstUnit {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstUnitVisitor)
+    }
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for ConstUnit {
+    type Deserializer = <() as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        ().into_deserializer()
+    }
+}
+
+struct ConstUnitVisitor;
+
+impl<'de> Visitor<'de> for ConstUnitVisitor {
+    type Value = ConstUnit;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant null")
+    }
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ConstUnit)
+    }
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ConstUnit)
+    }
+}
+
+/// A const empty string, i.e. a field that is always `""`.
+///
+/// Deserialization fails with [`invalid_length`](de::Error::invalid_length) if the string isn't
+/// empty. Unlike `ConstStr<"">`, this doesn't need the `nightly` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// use serde_constant::ConstEmptyStr;
+/// assert_eq!(serde_json::to_value(ConstEmptyStr).unwrap(), json!(""));
+/// assert!(serde_json::from_value::<ConstEmptyStr>(json!("")).is_ok());
+/// assert_eq!(
+///     serde_json::from_value::<ConstEmptyStr>(json!("abc")).unwrap_err().to_string(),
+///     "invalid length 3, expected the constant empty string",
+/// );
+/// assert!(serde_json::from_value::<ConstEmptyStr>(json!(null)).is_err());
+///
+/// #[derive(Deserialize, Debug)]
+/// #[serde(untagged)]
+/// enum Nickname {
+///     Unset(ConstEmptyStr),
+///     Set(String),
+/// }
+/// assert!(matches!(serde_json::from_value(json!("")), Ok(Nickname::Unset(_))));
+/// assert!(matches!(serde_json::from_value(json!("Al")), Ok(Nickname::Set(_))));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+pub struct ConstEmptyStr;
+
+impl ConstEmptyStr {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstEmptyStr;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+impl ConstValue for ConstEmptyStr {
+    type Repr = &'static str;
+    const VALUE: &'static str = "";
+}
+
+impl fmt::Display for ConstEmptyStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"\"")
+    }
+}
+
+impl Serialize for ConstEmptyStr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("")
+    }
+}
+
+impl<'de> Deserialize<'de> for ConstEmptyStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ConstEmptyStrVisitor)
+    }
+}
+
+impl<'de> DeserializeAny<'de> for ConstEmptyStr {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstEmptyStrVisitor)
+    }
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for ConstEmptyStr {
+    type Deserializer = <&'static str as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        "".into_deserializer()
+    }
+}
+
+struct ConstEmptyStrVisitor;
+
+impl<'de> Visitor<'de> for ConstEmptyStrVisitor {
+    type Value = ConstEmptyStr;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant empty string")
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.is_empty() {
+            Ok(ConstEmptyStr)
+        } else {
+            Err(E::invalid_length(v.chars().count(), &self))
+        }
+    }
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+/// A const empty sequence, i.e. a field that is always `[]`.
+///
+/// Deserialization fails with [`invalid_length`](de::Error::invalid_length) if the sequence isn't
+/// empty. The elements can be of any type, and are skipped to count them.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// use serde_constant::ConstEmptySeq;
+/// assert_eq!(serde_json::to_value(ConstEmptySeq).unwrap(), json!([]));
+/// assert!(serde_json::from_value::<ConstEmptySeq>(json!([])).is_ok());
+/// assert_eq!(
+///     serde_json::from_value::<ConstEmptySeq>(json!([1, "two", [3]])).unwrap_err().to_string(),
+///     "invalid length 3, expected the constant empty sequence",
+/// );
+/// assert!(serde_json::from_value::<ConstEmptySeq>(json!({})).is_err());
+///
+/// #[derive(Deserialize, Debug)]
+/// #[serde(untagged)]
+/// enum Batch {
+///     Done { items: ConstEmptySeq },
+///     Pending { items: Vec<u32> },
+/// }
+/// assert!(matches!(serde_json::from_value(json!({ "items": [] })), Ok(Batch::Done { .. })));
+/// assert!(matches!(serde_json::from_value(json!({ "items": [7] })), Ok(Batch::Pending { .. })));
+///
+/// let bytes = postcard::to_allocvec(&ConstEmptySeq).unwrap();
+/// assert_eq!(bytes, [0]);
+/// assert!(postcard::from_bytes::<ConstEmptySeq>(&bytes).is_ok());
+/// assert!(postcard::from_bytes::<ConstEmptySeq>(&postcard::to_allocvec(&[1u8]).unwrap()).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
+pub struct ConstEmptySeq;
+
+impl ConstEmptySeq {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstEmptySeq;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+impl ConstValue for ConstEmptySeq {
+    type Repr = [(); 0];
+    const VALUE: [(); 0] = [];
+}
+
+impl fmt::Display for ConstEmptySeq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[]")
+    }
+}
+
+impl Serialize for ConstEmptySeq {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serde::ser::SerializeSeq::end(serializer.serialize_seq(Some(0))?)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConstEmptySeq {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ConstEmptySeqVisitor)
+    }
+}
+
+impl<'de> DeserializeAny<'de> for ConstEmptySeq {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstEmptySeqVisitor)
+    }
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for ConstEmptySeq {
+    type Deserializer = de::value::SeqDeserializer<core::iter::Empty<()>, E>;
+    fn into_deserializer(self) -> Self::Deserializer {
+        de::value::SeqDeserializer::new(core::iter::empty())
+    }
+}
+
+struct ConstEmptySeqVisitor;
+
+impl<'de> Visitor<'de> for ConstEmptySeqVisitor {
+    type Value = ConstEmptySeq;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant empty sequence")
+    }
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        // formats that aren't self-describing, like `postcard`, can't skip elements of an unknown
+        // type, but they do know the length up front
+        if let Some(len @ 1..) = seq.size_hint() {
+            return Err(de::Error::invalid_length(len, &self));
+        }
+        let mut len = 0;
+        while seq.next_element::<de::IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+        if len == 0 {
+            Ok(ConstEmptySeq)
+        } else {
+            Err(de::Error::invalid_length(len, &self))
+        }
+    }
+}
+
+/// A const `&'static str`. Requires the `nightly` feature.
+///
+/// Deserialization fails if the value is not `V`.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// use serde_constant::ConstStr;
+/// #[derive(Deserialize)]
+/// #[serde(untagged)]
+/// enum Foo {
+///     User {
+///         r#type: ConstStr<"user">,
+///         name: String,
+///     },
+///     Group {
+///         r#type: ConstStr<"group">,
+///         name: String,
+///     },
+/// }
+///
+/// assert!(matches!(
+///     serde_json::from_value(json!({ "type": "group", "name": "admins" })),
+///     Ok(Foo::Group { .. }),
+/// ));
+/// assert!(serde_json::from_value::<Foo>(json!({ "type": "robot", "name": "r2d2" })).is_err());
+/// ```
+#[cfg(feature = "nightly")]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstStr<const V: &'static str>;
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> ConstStr<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstStr;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> ConstValue for ConstStr<V> {
+    type Repr = &'static str;
+    const VALUE: &'static str = V;
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> From<ConstStr<V>> for &'static str {
+    fn from(_: ConstStr<V>) -> &'static str {
+        V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> From<&ConstStr<V>> for &'static str {
+    fn from(_: &ConstStr<V>) -> &'static str {
+        V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, const V: &'static str> TryFrom<&'a str> for ConstStr<V> {
+    type Error = ConstMismatch<&'a str>;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        if value == V {
+            Ok(ConstStr::<V>)
+        } else {
+            Err(ConstMismatch {
+                expected: V,
+                found: value,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> fmt::Debug for ConstStr<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstStr").field(&V).finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> fmt::Display for ConstStr<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{V:?}")
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> Serialize for ConstStr<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(V)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static str> Deserialize<'de> for ConstStr<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ConstStrVisitor::<V>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static str> DeserializeAny<'de> for ConstStr<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstStrVisitor::<V>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static str, E: de::Error> IntoDeserializer<'de, E> for ConstStr<V> {
+    type Deserializer = <&'static str as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        V.into_deserializer()
+    }
+}
+
+#[cfg(feature = "nightly")]
+struct ConstStrVisitor<const V: &'static str>;
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static str> Visitor<'de> for ConstStrVisitor<V> {
+    type Value = ConstStr<V>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant str {}", ConstStr::<V>)
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v == V {
+            Ok(ConstStr::<V>)
+        } else {
+            Err(E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+}
+
+/// A const unit variant name, as found in the tag field of an internally tagged enum. Requires the
+/// `nightly` feature.
+///
+/// This behaves like [`ConstStr`], but reports mismatches as a variant rather than a string.
+/// Deserialization fails if the value is not `V`.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// use serde_constant::ConstUnitVariant;
+/// #[derive(Deserialize)]
+/// #[serde(untagged)]
+/// enum Shape {
+///     Circle {
+///         kind: ConstUnitVariant<"circle">,
+///         radius: f64,
+///     },
+///     Square {
+///         kind: ConstUnitVariant<"square">,
+///         radius: f64,
+///     },
+/// }
+///
+/// assert!(matches!(
+///     serde_json::from_value(json!({ "kind": "square", "radius": 1.0 })),
+///     // would have been Circle if `kind` were just `String`
+///     Ok(Shape::Square { .. }),
+/// ));
+///
+/// let err = serde_json::from_value::<ConstUnitVariant<"circle">>(json!("square")).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     r#"invalid value: string "square", expected the constant variant "circle""#,
+/// );
+/// ```
+#[cfg(feature = "nightly")]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstUnitVariant<const V: &'static str>;
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> ConstUnitVariant<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstUnitVariant;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> ConstValue for ConstUnitVariant<V> {
+    type Repr = &'static str;
+    const VALUE: &'static str = V;
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> From<ConstUnitVariant<V>> for &'static str {
+    fn from(_: ConstUnitVariant<V>) -> &'static str {
+        V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> From<&ConstUnitVariant<V>> for &'static str {
+    fn from(_: &ConstUnitVariant<V>) -> &'static str {
+        V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> From<ConstStr<V>> for ConstUnitVariant<V> {
+    fn from(_: ConstStr<V>) -> Self {
+        ConstUnitVariant
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> From<ConstUnitVariant<V>> for ConstStr<V> {
+    fn from(_: ConstUnitVariant<V>) -> Self {
+        ConstStr
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, const V: &'static str> TryFrom<&'a str> for ConstUnitVariant<V> {
+    type Error = ConstMismatch<&'a str>;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        ConstStr::<V>::try_from(value).map(Into::into)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> fmt::Debug for ConstUnitVariant<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstUnitVariant").field(&V).finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> fmt::Display for ConstUnitVariant<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{V:?}")
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static str> Serialize for ConstUnitVariant<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(V)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static str> Deserialize<'de> for ConstUnitVariant<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ConstUnitVariantVisitor::<V>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static str> DeserializeAny<'de> for ConstUnitVariant<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstUnitVariantVisitor::<V>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static str, E: de::Error> IntoDeserializer<'de, E> for ConstUnitVariant<V> {
+    type Deserializer = <&'static str as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        V.into_deserializer()
+    }
+}
+
+#[cfg(feature = "nightly")]
+struct ConstUnitVariantVisitor<const V: &'static str>;
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static str> Visitor<'de> for ConstUnitVariantVisitor<V> {
+    type Value = ConstUnitVariant<V>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant variant {}", ConstUnitVariant::<V>)
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v == V {
+            Ok(ConstUnitVariant::<V>)
+        } else {
+            Err(E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+}
+
+/// A const `&'static [u8]`, such as a magic number. Requires the `nightly` feature.
+///
+/// This is serialized with [`Serializer::serialize_bytes`]. Deserialization accepts byte strings
+/// and sequences of `u8`, and fails unless the bytes are exactly `V`.
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::ConstBytes;
+/// use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+/// type Magic = ConstBytes<b"\x89PNG">;
+///
+/// assert_tokens(&Magic::default(), &[Token::Bytes(b"\x89PNG")]);
+/// assert_de_tokens(
+///     &Magic::default(),
+///     &[
+///         Token::Seq { len: Some(4) },
+///         Token::U8(0x89),
+///         Token::U8(b'P'),
+///         Token::U8(b'N'),
+///         Token::U8(b'G'),
+///         Token::SeqEnd,
+///     ],
+/// );
+/// assert_de_tokens_error::<Magic>(
+///     &[Token::Bytes(b"GIF8")],
+///     r#"invalid value: byte array, expected the constant bytes b"\x89PNG""#,
+/// );
+/// assert_de_tokens_error::<Magic>(
+///     &[Token::Bytes(b"\x89PN")],
+///     r#"invalid value: byte array, expected the constant bytes b"\x89PNG""#,
+/// );
+/// ```
+#[cfg(feature = "nightly")]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstBytes<const V: &'static [u8]>;
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static [u8]> ConstBytes<V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstBytes;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static [u8]> ConstValue for ConstBytes<V> {
+    type Repr = &'static [u8];
+    const VALUE: &'static [u8] = V;
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static [u8]> From<ConstBytes<V>> for &'static [u8] {
+    fn from(_: ConstBytes<V>) -> &'static [u8] {
+        V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static [u8]> From<&ConstBytes<V>> for &'static [u8] {
+    fn from(_: &ConstBytes<V>) -> &'static [u8] {
+        V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, const V: &'static [u8]> TryFrom<&'a [u8]> for ConstBytes<V> {
+    type Error = ConstMismatch<&'a [u8]>;
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        if value == V {
+            Ok(ConstBytes::<V>)
+        } else {
+            Err(ConstMismatch {
+                expected: V,
+                found: value,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static [u8]> fmt::Debug for ConstBytes<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstBytes").field(&V).finish()
+    }
+}
+
+/// Writes `V` as a byte string literal, e.g. `b"\x89PNG"`.
+#[cfg(feature = "nightly")]
+impl<const V: &'static [u8]> fmt::Display for ConstBytes<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("b\"")?;
+        for &b in V {
+            write!(f, "{}", b.escape_ascii())?;
+        }
+        f.write_str("\"")
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: &'static [u8]> Serialize for ConstBytes<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(V)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static [u8]> Deserialize<'de> for ConstBytes<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(ConstBytesVisitor::<V>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static [u8]> DeserializeAny<'de> for ConstBytes<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstBytesVisitor::<V>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static [u8], E: de::Error> IntoDeserializer<'de, E> for ConstBytes<V> {
+    type Deserializer = <&'static [u8] as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        V.into_deserializer()
+    }
+}
+
+#[cfg(feature = "nightly")]
+struct ConstBytesVisitor<const V: &'static [u8]>;
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: &'static [u8]> Visitor<'de> for ConstBytesVisitor<V> {
+    type Value = ConstBytes<V>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant bytes {}", ConstBytes::<V>)
+    }
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v == V {
+            Ok(ConstBytes::<V>)
+        } else {
+            Err(E::invalid_value(Unexpected::Bytes(v), &self))
+        }
+    }
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(v)
+    }
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        // compare as we go, since there's nowhere to collect the bytes without allocating
+        for &expected in V {
+            if seq.next_element::<u8>()? != Some(expected) {
+                return Err(de::Error::invalid_value(Unexpected::Seq, &self));
+            }
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_value(Unexpected::Seq, &self));
+        }
+        Ok(ConstBytes::<V>)
+    }
+}
+
+/// A constant of any type that can be a const parameter. Requires the `nightly` feature.
+///
+/// This is a single type covering [`ConstBool`], [`ConstChar`], `ConstStr`, `ConstBytes`, and the
+/// integer constants, which it defers to for serialization. `Const<i64, 2>` behaves exactly like
+/// `ConstI64<2>`. The type has to be written out, since Rust can't infer it from the value. Naming
+/// `Const<&str, _>` or `Const<&[u8], _>` needs the `unsized_const_params` feature in your crate.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::{Const, ConstValue};
+/// assert!(serde_json::from_value::<Const<i64, 2>>(json!(2)).is_ok());
+/// assert!(serde_json::from_value::<Const<i64, 2>>(json!(3)).is_err());
+/// assert!(serde_json::from_value::<Const<char, 'x'>>(json!("x")).is_ok());
+/// assert!(serde_json::from_value::<Const<char, 'x'>>(json!("y")).is_err());
+///
+/// assert_eq!(serde_json::to_value(Const::<bool, true>::new()).unwrap(), json!(true));
+/// assert_eq!(Const::<u8, 5>::VALUE, 5);
+/// assert_eq!(Const::<u8, 5>::new().to_string(), "5");
+/// ```
+#[cfg(feature = "nightly")]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Const<T: ConstParamTy_, const V: T>(PhantomData<fn() -> T>);
+
+#[cfg(feature = "nightly")]
+impl<T: ConstParamTy_, const V: T> Const<T, V> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = Const(PhantomData);
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+// derived, this would need `T: Default`
+#[cfg(feature = "nightly")]
+impl<T: ConstParamTy_, const V: T> Default for Const<T, V> {
+    fn default() -> Self {
+        Self::NEW
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: ConstParamTy_ + Copy, const V: T> ConstValue for Const<T, V> {
+    type Repr = T;
+    const VALUE: T = V;
+}
+
+#[cfg(feature = "nightly")]
+impl<T: ConstParamTy_ + Hash, const V: T> Hash for Const<T, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        V.hash(state)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: ConstParamTy_ + fmt::Debug, const V: T> fmt::Debug for Const<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Const").field(&V).finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+macro_rules! impl_const {
+    ($($type:ty => $struct:ident),* $(,)?) => {
+        $(
+            impl<const V: $type> fmt::Display for Const<$type, V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&$struct::<V>, f)
+                }
+            }
+
+            impl<const V: $type> Serialize for Const<$type, V> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    $struct::<V>.serialize(serializer)
+                }
+            }
+
+            impl<'de, const V: $type> Deserialize<'de> for Const<$type, V> {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    $struct::<V>::deserialize(deserializer).map(|_| Const::new())
+                }
+            }
+
+            impl<const V: $type> sealed::Sealed for Const<$type, V> {}
+
+            impl<const V: $type> AnyConst for Const<$type, V> {}
+        )*
+    };
+}
+
+#[cfg(feature = "nightly")]
+impl_const!(
+    bool => ConstBool,
+    i8 => ConstI8,
+    i16 => ConstI16,
+    i32 => ConstI32,
+    i64 => ConstI64,
+    i128 => ConstI128,
+    isize => ConstIsize,
+    u8 => ConstU8,
+    u16 => ConstU16,
+    u32 => ConstU32,
+    u64 => ConstU64,
+    u128 => ConstU128,
+    usize => ConstUsize,
+    char => ConstChar,
+    &'static str => ConstStr,
+    &'static [u8] => ConstBytes,
+);
+
+/// A const `i64` that accepts any integer, recording whether it matched.
+///
+/// Deserialization only fails if the value isn't an integer. A different integer still produces
+/// the constant, and [`matched`](Self::matched) reports that it didn't match `V`, so that a
+/// pipeline can log the mismatch and continue. It always serializes as `V`.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstLenientI64;
+/// let version: ConstLenientI64<2> = serde_json::from_value(json!(2)).unwrap();
+/// assert!(version.matched());
+///
+/// let version: ConstLenientI64<2> = serde_json::from_value(json!(3)).unwrap();
+/// assert!(!version.matched());
+/// assert_eq!(serde_json::to_value(version).unwrap(), json!(2));
+///
+/// assert!(!serde_json::from_value::<ConstLenientI64<2>>(json!(u64::MAX)).unwrap().matched());
+/// assert!(serde_json::from_value::<ConstLenientI64<2>>(json!("2")).is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct ConstLenientI64<const V: i64> {
+    matched: bool,
+}
+
+impl<const V: i64> ConstLenientI64<V> {
+    /// Returns whether the deserialized value was `V`. Constants that weren't deserialized always
+    /// match.
+    pub const fn matched(self) -> bool {
+        self.matched
+    }
+}
+
+impl<const V: i64> Default for ConstLenientI64<V> {
+    fn default() -> Self {
+        ConstLenientI64 { matched: true }
+    }
+}
+
+impl<const V: i64> ConstValue for ConstLenientI64<V> {
+    type Repr = i64;
+    const VALUE: i64 = V;
+}
+
+impl<const V: i64> From<ConstI64<V>> for ConstLenientI64<V> {
+    fn from(_: ConstI64<V>) -> Self {
+        ConstLenientI64 { matched: true }
+    }
+}
+
+impl<const V: i64> From<ConstLenientI64<V>> for i64 {
+    fn from(_: ConstLenientI64<V>) -> i64 {
+        V
+    }
+}
+
+impl<const V: i64> fmt::Debug for ConstLenientI64<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConstLenientI64")
+            .field("value", &V)
+            .field("matched", &self.matched)
+            .finish()
+    }
+}
+
+impl<const V: i64> fmt::Display for ConstLenientI64<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{V}")
+    }
+}
+
+impl<const V: i64> Serialize for ConstLenientI64<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(V)
+    }
+}
+
+impl<'de, const V: i64> Deserialize<'de> for ConstLenientI64<V> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // `serde_json` rejects arbitrary-precision numbers that don't fit the hint
+        #[cfg(feature = "serde-json-arbitrary-precision")]
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_any(ConstLenientI64Visitor::<V>);
+        }
+        deserializer.deserialize_i64(ConstLenientI64Visitor::<V>)
+    }
+}
+
+impl<'de, const V: i64> DeserializeAny<'de> for ConstLenientI64<V> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstLenientI64Visitor::<V>)
+    }
+}
+
+struct ConstLenientI64Visitor<const V: i64>;
+
+impl<'de, const V: i64> Visitor<'de> for ConstLenientI64Visitor<V> {
+    type Value = ConstLenientI64<V>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an integer, ideally the constant i64 {V}")
+    }
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_i128(v as i128)
+    }
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ConstLenientI64 {
+            matched: v == V as i128,
+        })
+    }
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_i128(v as i128)
+    }
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ConstLenientI64 {
+            matched: v == V as u128 && V >= 0,
+        })
+    }
+    #[cfg(feature = "serde-json-arbitrary-precision")]
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        visit_arbitrary_precision(self, map)
+    }
+}
+
+/// One of a fixed set of `i64` constants. Requires the `nightly` feature.
+///
+/// Deserialization fails if the value is not in `VALUES`. Unlike the other constants, this stores
+/// which value it matched, so that it serializes back to the same value. The [`Default`] value is
+/// the first element of `VALUES`, and using this type with an empty `VALUES` is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::ConstOneOfI64;
+/// type Version = ConstOneOfI64<{ &[1, 2, 3] }>;
+///
+/// let version: Version = serde_json::from_value(json!(2)).unwrap();
+/// assert_eq!(version.get(), 2);
+/// assert_eq!(serde_json::to_value(version).unwrap(), json!(2));
+/// assert_eq!(Version::default().get(), 1);
+/// assert_eq!(
+///     serde_json::from_value::<Version>(json!(4)).unwrap_err().to_string(),
+///     "invalid value: integer `4`, expected one of the constant i64s 1, 2, 3",
+/// );
+/// assert_eq!(Version::new(3).map(Version::get), Some(3));
+/// assert_eq!(Version::new(4), None);
+/// ```
+#[cfg(feature = "nightly")]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct ConstOneOfI64<const VALUES: &'static [i64]> {
+    value: i64,
+}
+
+#[cfg(feature = "nightly")]
+impl<const VALUES: &'static [i64]> ConstOneOfI64<VALUES> {
+    const FIRST: i64 = match VALUES.first() {
+        Some(&v) => v,
+        None => panic!("`ConstOneOfI64` needs at least one value"),
+    };
+
+    /// Returns `value` as a constant, or `None` if it's not in `VALUES`.
+    pub fn new(value: i64) -> Option<Self> {
+        let _ = Self::FIRST;
+        VALUES.contains(&value).then_some(ConstOneOfI64 { value })
+    }
+
+    /// Returns the matched value.
+    pub const fn get(self) -> i64 {
+        self.value
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const VALUES: &'static [i64]> Default for ConstOneOfI64<VALUES> {
+    fn default() -> Self {
+        ConstOneOfI64 { value: Self::FIRST }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const VALUES: &'static [i64]> From<ConstOneOfI64<VALUES>> for i64 {
+    fn from(value: ConstOneOfI64<VALUES>) -> i64 {
+        value.value
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const VALUES: &'static [i64]> From<&ConstOneOfI64<VALUES>> for i64 {
+    fn from(value: &ConstOneOfI64<VALUES>) -> i64 {
+        value.value
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const VALUES: &'static [i64]> fmt::Debug for ConstOneOfI64<VALUES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstOneOfI64").field(&self.value).finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const VALUES: &'static [i64]> fmt::Display for ConstOneOfI64<VALUES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const VALUES: &'static [i64]> Serialize for ConstOneOfI64<VALUES> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(self.value)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const VALUES: &'static [i64]> Deserialize<'de> for ConstOneOfI64<VALUES> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let _ = Self::FIRST;
+        deserializer.deserialize_i64(ConstOneOfI64Visitor::<VALUES>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const VALUES: &'static [i64]> DeserializeAny<'de> for ConstOneOfI64<VALUES> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let _ = Self::FIRST;
+        deserializer.deserialize_any(ConstOneOfI64Visitor::<VALUES>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+struct ConstOneOfI64Visitor<const VALUES: &'static [i64]>;
+
+#[cfg(feature = "nightly")]
+impl<'de, const VALUES: &'static [i64]> Visitor<'de> for ConstOneOfI64Visitor<VALUES> {
+    type Value = ConstOneOfI64<VALUES>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("one of the constant i64s ")?;
+        for (i, v) in VALUES.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(", ")?;
+            }
+            write!(formatter, "{v}")?;
+        }
+        Ok(())
+    }
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        ConstOneOfI64::new(v).ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match i64::try_from(v) {
+            Ok(v) => self.visit_i64(v),
+            Err(_) => Err(invalid_i128(v, &self)),
+        }
+    }
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_u128(v as u128)
+    }
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match i64::try_from(v) {
+            Ok(v) => self.visit_i64(v),
+            Err(_) => Err(invalid_u128(v, &self)),
+        }
+    }
+}
+
+/// A const `i64` that names its field in error messages. Requires the `nightly` feature.
+///
+/// This is a [`ConstI64`] whose [`Visitor::expecting`] mentions `NAME`, for formats that don't
+/// report which field failed to deserialize. Otherwise it serializes, deserializes, parses, and
+/// formats exactly like `ConstI64<V>`, and deserialization goes through `ConstI64` itself, so the
+/// same features apply.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde::Deserialize;
+/// use serde_constant::ConstLabeled;
+///
+/// #[derive(Deserialize, Debug)]
+/// struct Header {
+///     version: ConstLabeled<2, "version">,
+/// }
+///
+/// #[derive(Deserialize, Debug)]
+/// struct Packet {
+///     header: Header,
+/// }
+///
+/// assert!(serde_json::from_value::<Packet>(json!({ "header": { "version": 2 } })).is_ok());
+/// let err = serde_json::from_value::<Packet>(json!({ "header": { "version": 3 } })).unwrap_err();
+/// # #[cfg(not(feature = "alloc"))]
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid value: integer `3`, expected the constant i64 2 for field \"version\"",
+/// );
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(
+///     err.to_string(),
+///     "expected the constant i64 2 for field \"version\", found 3",
+/// );
+/// assert_eq!(serde_json::to_value(ConstLabeled::<2, "version">).unwrap(), json!(2));
+///
+/// type Version = ConstLabeled<2, "version">;
+/// assert_eq!(Version::NEW, 2);
+/// assert_eq!(2, Version::NEW);
+/// assert!(Version::NEW < 3);
+/// assert!("2".parse::<Version>().is_ok());
+/// assert_eq!(format!("{:#x}", Version::NEW), "0x2");
+/// ```
+#[cfg(feature = "nightly")]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstLabeled<const V: i64, const NAME: &'static str>;
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> ConstLabeled<V, NAME> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstLabeled;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+
+    /// The underlying value.
+    pub const VALUE: i64 = V;
+
+    /// The field name used in error messages.
+    pub const NAME: &'static str = NAME;
+
+    /// Returns the underlying value.
+    pub const fn get(self) -> i64 {
+        Self::VALUE
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> ConstValue for ConstLabeled<V, NAME> {
+    type Repr = i64;
+    const VALUE: i64 = V;
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> Hash for ConstLabeled<V, NAME> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        V.hash(state)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> From<ConstLabeled<V, NAME>> for i64 {
+    fn from(_: ConstLabeled<V, NAME>) -> i64 {
+        V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> From<&ConstLabeled<V, NAME>> for i64 {
+    fn from(_: &ConstLabeled<V, NAME>) -> i64 {
+        V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> From<ConstI64<V>> for ConstLabeled<V, NAME> {
+    fn from(_: ConstI64<V>) -> Self {
+        ConstLabeled
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> From<ConstLabeled<V, NAME>> for ConstI64<V> {
+    fn from(_: ConstLabeled<V, NAME>) -> Self {
+        ConstI64
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> TryFrom<i64> for ConstLabeled<V, NAME> {
+    type Error = ConstMismatch<i64>;
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        ConstI64::<V>::try_from(value).map(ConstLabeled::<V, NAME>::from)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> FromStr for ConstLabeled<V, NAME> {
+    type Err = ParseConstError<i64>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_const(s)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> PartialEq<i64> for ConstLabeled<V, NAME> {
+    fn eq(&self, other: &i64) -> bool {
+        V == *other
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> PartialEq<ConstLabeled<V, NAME>> for i64 {
+    fn eq(&self, _: &ConstLabeled<V, NAME>) -> bool {
+        *self == V
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> PartialOrd<i64> for ConstLabeled<V, NAME> {
+    fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+        Some(V.cmp(other))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> PartialOrd<ConstLabeled<V, NAME>> for i64 {
+    fn partial_cmp(&self, _: &ConstLabeled<V, NAME>) -> Option<Ordering> {
+        Some(self.cmp(&V))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> fmt::Debug for ConstLabeled<V, NAME> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstLabeled")
+            .field(&V)
+            .field(&NAME)
+            .finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> fmt::Display for ConstLabeled<V, NAME> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&ConstI64::<V>, f)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> fmt::LowerHex for ConstLabeled<V, NAME> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&ConstI64::<V>, f)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> fmt::UpperHex for ConstLabeled<V, NAME> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&ConstI64::<V>, f)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> fmt::Binary for ConstLabeled<V, NAME> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&ConstI64::<V>, f)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> fmt::Octal for ConstLabeled<V, NAME> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&ConstI64::<V>, f)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> Serialize for ConstLabeled<V, NAME> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ConstI64::<V>.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: i64, const NAME: &'static str> Deserialize<'de> for ConstLabeled<V, NAME> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ConstI64::<V>::deserialize_labeled(deserializer, Some(NAME))
+            .map(ConstLabeled::<V, NAME>::from)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: i64, const NAME: &'static str> DeserializeAny<'de> for ConstLabeled<V, NAME> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ConstI64::<V>::deserialize_any_labeled(deserializer, Some(NAME))
+            .map(ConstLabeled::<V, NAME>::from)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: i64, const NAME: &'static str, E: de::Error> IntoDeserializer<'de, E>
+    for ConstLabeled<V, NAME>
+{
+    type Deserializer = <i64 as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        V.into_deserializer()
+    }
+}
+
+/// An `i64` constant chosen at runtime.
+///
+/// This is for values that aren't known at compile time, so can't be a [`ConstI64`]. Since
+/// [`Deserialize`] can't carry state, it's used through [`DeserializeSeed`](de::DeserializeSeed)
+/// instead, which fails if the value is not `value`.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde::de::DeserializeSeed;
+/// use serde_constant::DynConstI64;
+/// let tag = DynConstI64::new(7);
+/// assert_eq!(tag.deserialize(json!(7)).unwrap(), tag);
+/// # #[cfg(not(feature = "alloc"))]
+/// assert_eq!(
+///     tag.deserialize(json!(8)).unwrap_err().to_string(),
+///     "invalid value: integer `8`, expected the constant i64 7",
+/// );
+/// assert_eq!(serde_json::to_value(tag).unwrap(), json!(7));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynConstI64 {
+    /// The value of the constant.
+    pub value: i64,
+}
+
+impl DynConstI64 {
+    /// Creates a constant with the given value.
+    pub const fn new(value: i64) -> Self {
+        DynConstI64 { value }
+    }
+}
+
+impl fmt::Display for DynConstI64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Serialize for DynConstI64 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(self.value)
+    }
+}
+
+impl<'de> de::DeserializeSeed<'de> for DynConstI64 {
+    type Value = DynConstI64;
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_i64(self)
+    }
+}
+
+impl<'de> Visitor<'de> for DynConstI64 {
+    type Value = DynConstI64;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant i64 {self}")
+    }
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_i128(v as i128)
+    }
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        check_i128(v, self.value as i128, &self).map(|()| self)
+    }
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_i128(v as i128)
+    }
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match i128::try_from(v) {
+            Ok(v) => self.visit_i128(v),
+            Err(_) => Err(invalid_u128(v, &self)),
+        }
+    }
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        #[cfg(feature = "accept-whole-floats")]
+        return match f64_to_i128(v) {
+            Some(v) => self.visit_i128(v),
+            None => Err(E::invalid_value(Unexpected::Float(v), &self)),
+        };
+        #[cfg(not(feature = "accept-whole-floats"))]
+        Err(E::invalid_type(Unexpected::Float(v), &self))
+    }
+}
+
+/// A `bool` constant chosen at runtime. See [`DynConstI64`].
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde::de::DeserializeSeed;
+/// use serde_constant::DynConstBool;
+/// assert!(DynConstBool::new(true).deserialize(json!(true)).is_ok());
+/// assert!(DynConstBool::new(true).deserialize(json!(false)).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynConstBool {
+    /// The value of the constant.
+    pub value: bool,
+}
+
+impl DynConstBool {
+    /// Creates a constant with the given value.
+    pub const fn new(value: bool) -> Self {
+        DynConstBool { value }
+    }
+}
+
+impl fmt::Display for DynConstBool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Serialize for DynConstBool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.value)
+    }
+}
+
+impl<'de> de::DeserializeSeed<'de> for DynConstBool {
+    type Value = DynConstBool;
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bool(self)
+    }
+}
+
+impl<'de> Visitor<'de> for DynConstBool {
+    type Value = DynConstBool;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant bool {self}")
+    }
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v == self.value {
+            Ok(self)
+        } else {
+            Err(E::invalid_value(Unexpected::Bool(v), &self))
+        }
+    }
+}
+
+/// A `char` constant chosen at runtime. See [`DynConstI64`].
+///
+/// Like [`ConstChar`], this is written as a codepoint in formats that aren't human-readable, so
+/// the two can read each other's output.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde::de::DeserializeSeed;
+/// use serde_constant::{ConstChar, DynConstChar};
+/// assert!(DynConstChar::new('x').deserialize(json!("x")).is_ok());
+/// assert!(DynConstChar::new('x').deserialize(json!("y")).is_err());
+/// assert!(DynConstChar::new('x').deserialize(json!("xx")).is_err());
+///
+/// let bytes = postcard::to_allocvec(&ConstChar::<'é'>).unwrap();
+/// assert_eq!(bytes, postcard::to_allocvec(&DynConstChar::new('é')).unwrap());
+/// let mut de = postcard::Deserializer::from_bytes(&bytes);
+/// assert!(DynConstChar::new('é').deserialize(&mut de).is_ok());
+/// let mut de = postcard::Deserializer::from_bytes(&bytes);
+/// assert!(DynConstChar::new('e').deserialize(&mut de).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynConstChar {
+    /// The value of the constant.
+    pub value: char,
+}
+
+impl DynConstChar {
+    /// Creates a constant with the given value.
+    pub const fn new(value: char) -> Self {
+        DynConstChar { value }
+    }
+}
+
+impl fmt::Display for DynConstChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.value)
+    }
+}
+
+impl Serialize for DynConstChar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_char(self.value)
+        } else {
+            serializer.serialize_u32(self.value as u32)
+        }
+    }
+}
+
+impl<'de> de::DeserializeSeed<'de> for DynConstChar {
+    type Value = DynConstChar;
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_u32(self);
+        }
+        // hinted deserialize calls never hand integers to the visitor
+        #[cfg(feature = "char-codepoints")]
+        return deserializer.deserialize_any(self);
+        #[cfg(not(feature = "char-codepoints"))]
+        deserializer.deserialize_char(self)
+    }
+}
+
+impl<'de> Visitor<'de> for DynConstChar {
+    type Value = DynConstChar;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant char {self}")
+    }
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v == self.value {
+            Ok(self)
+        } else {
+            Err(E::invalid_value(Unexpected::Char(v), &self))
+        }
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let mut chs = v.chars();
+        let ch = chs.next().ok_or_else(|| E::invalid_length(0, &self))?;
+        if chs.next().is_some() {
+            Err(invalid_char_str(v, v, &self))
+        } else {
+            self.visit_char(ch)
+        }
+    }
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // surrogates and values past U+10FFFF aren't chars
+        match u32::try_from(v).ok().and_then(char::from_u32) {
+            Some(ch) if ch == self.value => Ok(self),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+}
+
+/// Whether `f` is exactly the integer `i`.
+fn float_is_int(f: f64, i: i128) -> bool {
+    f64_to_i128(f) == Some(i)
+}
+
+macro_rules! declare_float {
+    ($($type:ident($bits:ty) => $struct:ident $visitor:ident $macro:ident $ser_func:ident $deser_func:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("A const `", stringify!($type), "`, keyed on its bit pattern.")]
+            ///
+            #[doc = concat!("Stable Rust doesn't allow `", stringify!($type), "` as a const parameter, so `BITS` is the value of [`", stringify!($type), "::to_bits`].")]
+            #[doc = concat!("You'll usually want to write the type with the [`", stringify!($macro), "!`] macro instead.")]
+            ///
+            #[doc = concat!("Deserialization fails if the value is not numerically equal to [`", stringify!($struct), "::value`]. This means")]
+            /// `0.0` and `-0.0` match each other despite having different bit patterns. NaN is never equal to
+            #[doc = concat!("anything, so a `", stringify!($struct), "` with a NaN bit pattern will never successfully deserialize.")]
+            /// Use [`ConstNanF64`] to match any NaN instead.
+            #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+            pub struct $struct<const BITS: $bits>;
+
+            impl<const BITS: $bits> $struct<BITS> {
+                /// The constant, for `const` items and struct literals.
+                pub const NEW: Self = $struct;
+
+                /// Returns the constant.
+                pub const fn new() -> Self {
+                    Self::NEW
+                }
+
+                /// Returns the value represented by `BITS`.
+                pub const fn value() -> $type {
+                    $type::from_bits(BITS)
+                }
+            }
+
+            impl<const BITS: $bits> ConstValue for $struct<BITS> {
+                type Repr = $type;
+                const VALUE: $type = Self::value();
+            }
+
+            impl<const BITS: $bits> From<$struct<BITS>> for $type {
+                fn from(_: $struct<BITS>) -> $type {
+                    $struct::<BITS>::value()
+                }
+            }
+
+            impl<const BITS: $bits> From<&$struct<BITS>> for $type {
+                fn from(_: &$struct<BITS>) -> $type {
+                    $struct::<BITS>::value()
+                }
+            }
+
+            impl<const BITS: $bits> TryFrom<$type> for $struct<BITS> {
+                type Error = ConstMismatch<$type>;
+                fn try_from(value: $type) -> Result<Self, Self::Error> {
+                    if value == $struct::<BITS>::value() {
+                        Ok($struct::<BITS>)
+                    } else {
+                        Err(ConstMismatch {
+                            expected: $struct::<BITS>::value(),
+                            found: value,
+                        })
+                    }
+                }
+            }
+
+            impl<const BITS: $bits> FromStr for $struct<BITS> {
+                type Err = ParseConstError<$type>;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_const(s)
+                }
+            }
+
+            impl<const BITS: $bits> fmt::Debug for $struct<BITS> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($struct)).field(&Self::value()).finish()
+                }
+            }
+
+            impl<const BITS: $bits> fmt::Display for $struct<BITS> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", Self::value())
+                }
+            }
+
+            impl<const BITS: $bits> Serialize for $struct<BITS> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.$ser_func(Self::value())
+                }
+            }
+
+            impl<'de, const BITS: $bits> Deserialize<'de> for $struct<BITS> {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    // buffered content, e.g. under `#[serde(flatten)]`, holds `serde_json`'s
+                    // arbitrary-precision numbers as maps, which the hinted call rejects
+                    #[cfg(feature = "serde-json-arbitrary-precision")]
+                    if deserializer.is_human_readable() {
+                        return deserializer.deserialize_any($visitor::<BITS>);
+                    }
+                    deserializer.$deser_func($visitor::<BITS>)
+                }
+            }
+
+            impl<'de, const BITS: $bits> DeserializeAny<'de> for $struct<BITS> {
+                fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_any($visitor::<BITS>)
+                }
+            }
+
+            impl<'de, const BITS: $bits, E: de::Error> IntoDeserializer<'de, E> for $struct<BITS> {
+                type Deserializer = <$type as IntoDeserializer<'de, E>>::Deserializer;
+                fn into_deserializer(self) -> Self::Deserializer {
+                    $struct::<BITS>::value().into_deserializer()
+                }
+            }
+
+            struct $visitor<const BITS: $bits>;
+
+            impl<'de, const BITS: $bits> Visitor<'de> for $visitor<BITS> {
+                type Value = $struct<BITS>;
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, concat!("the constant ", stringify!($type), " {}"), $struct::<BITS>)
+                }
+                fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    self.visit_f64(v as f64)
+                }
+                fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    // every f32 is exactly representable as an f64, so comparing here also rejects
+                    // f64 inputs that would only round to the constant
+                    if v == $struct::<BITS>::value() as f64 {
+                        Ok($struct::<BITS>)
+                    } else {
+                        Err(E::invalid_value(Unexpected::Float(v), &self))
+                    }
+                }
+                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    if float_is_int($struct::<BITS>::value() as f64, v as i128) {
+                        Ok($struct::<BITS>)
+                    } else {
+                        Err(E::invalid_value(Unexpected::Signed(v), &self))
+                    }
+                }
+                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    if float_is_int($struct::<BITS>::value() as f64, v as i128) {
+                        Ok($struct::<BITS>)
+                    } else {
+                        Err(E::invalid_value(Unexpected::Unsigned(v), &self))
+                    }
+                }
+                #[cfg(feature = "serde-json-arbitrary-precision")]
+                fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: de::MapAccess<'de>,
+                {
+                    visit_arbitrary_precision(self, map)
+                }
+            }
+        )*
+    };
+}
+
+declare_float!(
+    f32(u32) => ConstF32 ConstF32Visitor const_f32 serialize_f32 deserialize_f32,
+    f64(u64) => ConstF64 ConstF64Visitor const_f64 serialize_f64 deserialize_f64,
+);
+
+/// Expands to the [`ConstF32`] type for a given `f32` value.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// # use serde_test::{assert_de_tokens, assert_de_tokens_error, Compact, Configure, Token};
+/// use serde_constant::const_f32;
+/// type Foo = const_f32!(1.5);
+/// assert_eq!(Foo::value(), 1.5);
+///
+/// assert!(serde_json::from_value::<Foo>(json!(1.5)).is_ok());
+/// assert!(serde_json::from_value::<Foo>(json!(2.5)).is_err());
+///
+/// assert_de_tokens(&Foo::default().compact(), &[Token::F32(1.5)]);
+/// assert_de_tokens(&Foo::default().compact(), &[Token::F64(1.5)]);
+/// // out of range for f32
+/// assert!(serde_json::from_value::<Foo>(json!(1e40)).is_err());
+/// // rounds to 1.5f32, but isn't exactly 1.5
+/// assert_de_tokens_error::<Compact<Foo>>(
+///     &[Token::F64(1.5000000001)],
+///     "invalid value: floating point `1.5000000001`, expected the constant f32 1.5",
+/// );
+/// ```
+#[macro_export]
+macro_rules! const_f32 {
+    ($value:expr) => {
+        $crate::ConstF32<{ ::core::primitive::f32::to_bits($value) }>
+    };
+}
+
+/// Expands to the [`ConstF64`] type for a given `f64` value.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// use serde_constant::const_f64;
+/// #[derive(Deserialize)]
+/// struct Foo {
+///     bar: const_f64!(1.5),
+/// }
+///
+/// assert!(serde_json::from_value::<Foo>(json!({ "bar": 1.5 })).is_ok());
+/// assert!(serde_json::from_value::<Foo>(json!({ "bar": 2.5 })).is_err());
+///
+/// // signed zeros compare equal
+/// assert!(serde_json::from_value::<const_f64!(0.0)>(json!(-0.0)).is_ok());
+/// ```
+///
+/// Under `#[serde(flatten)]`, serde buffers the field before handing it over, and the constant is
+/// still checked.
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_constant::const_f64;
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Header {
+///     scale: const_f64!(1.5),
+/// }
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Message {
+///     body: String,
+///     #[serde(flatten)]
+///     header: Header,
+/// }
+///
+/// let json = r#"{"body":"hi","scale":1.5}"#;
+/// let message: Message = serde_json::from_str(json).unwrap();
+/// assert_eq!(serde_json::to_string(&message).unwrap(), json);
+/// assert!(serde_json::from_value::<Message>(json!({ "body": "hi", "scale": 2.5 })).is_err());
+/// ```
+#[macro_export]
+macro_rules! const_f64 {
+    ($value:expr) => {
+        $crate::ConstF64<{ ::core::primitive::f64::to_bits($value) }>
+    };
+}
+
+/// A const infinite `f64`: positive infinity if `SIGN` is `true`, negative infinity otherwise.
+///
+/// This is the same as `const_f64!(f64::INFINITY)` or `const_f64!(f64::NEG_INFINITY)`, but easier
+/// to read. Deserialization fails if the value is not the infinity of the given sign. Formats
+/// without infinities, such as JSON, can't round-trip it.
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::ConstInfinityF64;
+/// let mut bytes = Vec::new();
+/// ciborium::into_writer(&ConstInfinityF64::<true>, &mut bytes).unwrap();
+/// assert!(ciborium::from_reader::<ConstInfinityF64<true>, _>(&bytes[..]).is_ok());
+/// assert!(ciborium::from_reader::<ConstInfinityF64<false>, _>(&bytes[..]).is_err());
+///
+/// let mut bytes = Vec::new();
+/// ciborium::into_writer(&f64::MAX, &mut bytes).unwrap();
+/// assert!(ciborium::from_reader::<ConstInfinityF64<true>, _>(&bytes[..]).is_err());
+///
+/// assert_eq!(ConstInfinityF64::<false>.to_string(), "-inf");
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct ConstInfinityF64<const SIGN: bool>;
+
+impl<const SIGN: bool> ConstInfinityF64<SIGN> {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstInfinityF64;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+
+    /// Returns the infinity of sign `SIGN`.
+    pub const fn value() -> f64 {
+        if SIGN {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+}
+
+impl<const SIGN: bool> ConstValue for ConstInfinityF64<SIGN> {
+    type Repr = f64;
+    const VALUE: f64 = Self::value();
+}
+
+impl<const SIGN: bool> From<ConstInfinityF64<SIGN>> for f64 {
+    fn from(_: ConstInfinityF64<SIGN>) -> f64 {
+        ConstInfinityF64::<SIGN>::value()
+    }
+}
+
+impl<const SIGN: bool> From<&ConstInfinityF64<SIGN>> for f64 {
+    fn from(_: &ConstInfinityF64<SIGN>) -> f64 {
+        ConstInfinityF64::<SIGN>::value()
+    }
+}
+
+impl<const SIGN: bool> TryFrom<f64> for ConstInfinityF64<SIGN> {
+    type Error = ConstMismatch<f64>;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value == Self::value() {
+            Ok(ConstInfinityF64::<SIGN>)
+        } else {
+            Err(ConstMismatch {
+                expected: Self::value(),
+                found: value,
+            })
+        }
+    }
+}
+
+impl<const SIGN: bool> fmt::Debug for ConstInfinityF64<SIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstInfinityF64")
+            .field(&Self::value())
+            .finish()
+    }
+}
+
+impl<const SIGN: bool> fmt::Display for ConstInfinityF64<SIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Self::value())
+    }
+}
+
+impl<const SIGN: bool> Serialize for ConstInfinityF64<SIGN> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(Self::value())
+    }
+}
+
+impl<'de, const SIGN: bool> Deserialize<'de> for ConstInfinityF64<SIGN> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(ConstInfinityF64Visitor::<SIGN>)
+    }
+}
+
+impl<'de, const SIGN: bool> DeserializeAny<'de> for ConstInfinityF64<SIGN> {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstInfinityF64Visitor::<SIGN>)
+    }
+}
+
+impl<'de, const SIGN: bool, E: de::Error> IntoDeserializer<'de, E> for ConstInfinityF64<SIGN> {
+    type Deserializer = <f64 as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        ConstInfinityF64::<SIGN>::value().into_deserializer()
+    }
+}
+
+struct ConstInfinityF64Visitor<const SIGN: bool>;
+
+impl<'de, const SIGN: bool> Visitor<'de> for ConstInfinityF64Visitor<SIGN> {
+    type Value = ConstInfinityF64<SIGN>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the constant f64 {}", ConstInfinityF64::<SIGN>)
+    }
+    fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_f64(v as f64)
+    }
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v == ConstInfinityF64::<SIGN>::value() {
+            Ok(ConstInfinityF64::<SIGN>)
+        } else {
+            Err(E::invalid_value(Unexpected::Float(v), &self))
+        }
+    }
+}
+
+/// A const NaN `f64`, which matches any NaN.
 ///
-/// Deserialization fails if the value is not `V`.
+/// [`ConstF64`] compares by value, and NaN isn't equal to anything, so no `ConstF64` with a NaN
+/// bit pattern ever deserializes. This type checks [`f64::is_nan`] instead, accepting NaNs of any
+/// sign or payload. It always serializes as [`f64::NAN`], so a NaN with a different payload won't
+/// round-trip bit-for-bit. Formats without NaN, such as JSON, can't round-trip it at all.
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::ConstNanF64;
+/// let mut bytes = Vec::new();
+/// ciborium::into_writer(&ConstNanF64, &mut bytes).unwrap();
+/// assert!(ciborium::from_reader::<ConstNanF64, _>(&bytes[..]).is_ok());
+///
+/// let payload = f64::from_bits(f64::NAN.to_bits() | 1);
+/// let mut bytes = Vec::new();
+/// ciborium::into_writer(&-payload, &mut bytes).unwrap();
+/// assert!(ciborium::from_reader::<ConstNanF64, _>(&bytes[..]).is_ok());
+///
+/// let mut bytes = Vec::new();
+/// ciborium::into_writer(&f64::INFINITY, &mut bytes).unwrap();
+/// assert!(ciborium::from_reader::<ConstNanF64, _>(&bytes[..]).is_err());
+///
+/// assert!(bincode::deserialize::<ConstNanF64>(&bincode::serialize(&f64::NAN).unwrap()).is_ok());
+/// assert!(serde_json::from_value::<ConstNanF64>(serde_json::json!(null)).is_err());
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
-pub struct ConstBool<const V: bool>;
+pub struct ConstNanF64;
 
-impl<const V: bool> Serialize for ConstBool<V> {
+impl ConstNanF64 {
+    /// The constant, for `const` items and struct literals.
+    pub const NEW: Self = ConstNanF64;
+
+    /// Returns the constant.
+    pub const fn new() -> Self {
+        Self::NEW
+    }
+}
+
+impl ConstValue for ConstNanF64 {
+    type Repr = f64;
+    const VALUE: f64 = f64::NAN;
+}
+
+impl From<ConstNanF64> for f64 {
+    fn from(_: ConstNanF64) -> f64 {
+        f64::NAN
+    }
+}
+
+impl From<&ConstNanF64> for f64 {
+    fn from(_: &ConstNanF64) -> f64 {
+        f64::NAN
+    }
+}
+
+impl TryFrom<f64> for ConstNanF64 {
+    type Error = ConstMismatch<f64>;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.is_nan() {
+            Ok(ConstNanF64)
+        } else {
+            Err(ConstMismatch {
+                expected: f64::NAN,
+                found: value,
+            })
+        }
+    }
+}
+
+impl fmt::Display for ConstNanF64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NaN")
+    }
+}
+
+impl Serialize for ConstNanF64 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_bool(V)
+        serializer.serialize_f64(f64::NAN)
     }
 }
 
-impl<'de, const V: bool> Deserialize<'de> for ConstBool<V> {
+impl<'de> Deserialize<'de> for ConstNanF64 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bool(ConstBoolVisitor::<V>)
+        deserializer.deserialize_f64(ConstNanF64Visitor)
     }
 }
 
-struct ConstBoolVisitor<const V: bool>;
+impl<'de> DeserializeAny<'de> for ConstNanF64 {
+    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConstNanF64Visitor)
+    }
+}
 
-impl<'de, const V: bool> Visitor<'de> for ConstBoolVisitor<V> {
-    type Value = ConstBool<V>;
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for ConstNanF64 {
+    type Deserializer = <f64 as IntoDeserializer<'de, E>>::Deserializer;
+    fn into_deserializer(self) -> Self::Deserializer {
+        f64::NAN.into_deserializer()
+    }
+}
+
+struct ConstNanF64Visitor;
+
+impl<'de> Visitor<'de> for ConstNanF64Visitor {
+    type Value = ConstNanF64;
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "{V}")
+        write!(formatter, "the constant f64 NaN")
     }
-    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
     where
         E: de::Error,
     {
-        if v == V {
-            Ok(ConstBool::<V>)
+        self.visit_f64(v as f64)
+    }
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.is_nan() {
+            Ok(ConstNanF64)
         } else {
-            Err(E::invalid_value(Unexpected::Bool(v), &self))
+            Err(E::invalid_value(Unexpected::Float(v), &self))
         }
     }
 }
 
-macro_rules! declare_int {
-    ($($type:ty => $struct:ident $visitor:ident $ser_func:ident $deser_func:ident),* $(,)?) => {
-        $(
-            #[doc = concat!("A const `", stringify!($type), "`.")]
-            ///
-            /// Deserialization fails if the value is not `V`.
-            #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
-            pub struct $struct<const V: $type>;
+/// Declares a new constant type, for representations this crate doesn't provide.
+///
+/// The syntax is a struct declaration with a single const parameter named `V`, followed by the
+/// [`Serializer`] method used to write `V` and the [`Deserializer`] method used to read it:
+///
+/// ```text
+/// declare_const! {
+///     $(#[$attr])* $vis struct $name<const V: $type> => $serialize_method, $deserialize_method;
+/// }
+/// ```
+///
+/// `$type` can be `bool`, `char`, or any integer type. `V` is cast to the parameter type of
+/// `$serialize_method`, or passed by reference if it is `collect_str`.
+///
+/// The generated visitor implements `visit_bool`, `visit_i64`, `visit_i128`, `visit_u64`,
+/// `visit_u128`, `visit_char`, and `visit_str`. Each one converts its input to `$type` where that's
+/// lossless (strings are parsed with [`FromStr`](core::str::FromStr)), and deserialization fails
+/// unless the result is `V`.
+///
+/// The type also gets the same derives as the built-in constants, as well as [`Debug`](fmt::Debug),
+/// [`Display`](fmt::Display), and [`ConstValue`] implementations.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::declare_const;
+/// declare_const! {
+///     /// An order ID, which is sent as a string.
+///     pub struct ConstOrderId<const V: u64> => collect_str, deserialize_str;
+/// }
+///
+/// assert_eq!(serde_json::to_value(ConstOrderId::<123>).unwrap(), json!("123"));
+/// assert!(serde_json::from_value::<ConstOrderId<123>>(json!("123")).is_ok());
+/// assert!(serde_json::from_value::<ConstOrderId<123>>(json!("124")).is_err());
+/// assert!(serde_json::from_value::<ConstOrderId<123>>(json!(123)).is_err());
+/// ```
+#[macro_export]
+macro_rules! declare_const {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident<const V: $type:ty> => $ser_func:ident, $deser_func:ident $(;)?) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+        $vis struct $name<const V: $type>;
 
-            impl<const V: $type> Serialize for $struct<V> {
+        const _: () = {
+            use $crate::__private::{
+                fmt,
+                serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+                Primitive,
+            };
+
+            impl<const V: $type> $crate::ConstValue for $name<V> {
+                type Repr = $type;
+                const VALUE: $type = V;
+            }
+
+            impl<const V: $type> ::core::hash::Hash for $name<V> {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    V.hash(state)
+                }
+            }
+
+            impl<const V: $type> fmt::Debug for $name<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&V).finish()
+                }
+            }
+
+            impl<const V: $type> fmt::Display for $name<V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&V, f)
+                }
+            }
+
+            impl<const V: $type> Serialize for $name<V> {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
                     S: Serializer,
                 {
-                    serializer.$ser_func(V)
+                    $crate::__declare_const_serialize!(serializer.$ser_func(V))
                 }
             }
 
-            impl<'de, const V: $type> Deserialize<'de> for $struct<V> {
+            impl<'de, const V: $type> Deserialize<'de> for $name<V> {
                 fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
                 where
                     D: Deserializer<'de>,
                 {
-                    deserializer.$deser_func($visitor::<V>)
+                    deserializer.$deser_func(Visitor::<V>)
                 }
             }
 
-            struct $visitor<const V: $type>;
+            struct Visitor<const V: $type>;
 
-            impl<'de, const V: $type> Visitor<'de> for $visitor<V> {
-                type Value = $struct<V>;
+            impl<'de, const V: $type> de::Visitor<'de> for Visitor<V> {
+                type Value = $name<V>;
                 fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                    write!(formatter, "{V}")
+                    write!(formatter, concat!("the constant ", stringify!($type), " {}"), V)
                 }
-                fn visit_i8<E>(self, v: i8) -> Result<Self::Value, E>
+                fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
-                    self.visit_i128(v as i128)
+                    if <$type as Primitive>::from_bool(v) == Some(V) {
+                        Ok($name::<V>)
+                    } else {
+                        Err(E::invalid_value(de::Unexpected::Bool(v), &self))
+                    }
                 }
-                fn visit_i16<E>(self, v: i16) -> Result<Self::Value, E>
+                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
                     self.visit_i128(v as i128)
                 }
-                fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E>
+                fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
-                    self.visit_i128(v as i128)
+                    if <$type as Primitive>::from_i128(v) == Some(V) {
+                        Ok($name::<V>)
+                    } else {
+                        Err($crate::__private::invalid_i128(v, &self))
+                    }
                 }
-                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
-                    self.visit_i128(v as i128)
+                    self.visit_u128(v as u128)
                 }
-                fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+                fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
-                    if v == V as i128 {
-                        Ok($struct::<V>)
+                    if <$type as Primitive>::from_u128(v) == Some(V) {
+                        Ok($name::<V>)
                     } else {
-                        Err(E::invalid_value(Unexpected::Signed(V as i64), &self))
+                        Err($crate::__private::invalid_u128(v, &self))
                     }
                 }
-                fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
+                fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
-                    self.visit_i128(v as i128)
+                    if <$type as Primitive>::from_char(v) == Some(V) {
+                        Ok($name::<V>)
+                    } else {
+                        Err(E::invalid_value(de::Unexpected::Char(v), &self))
+                    }
                 }
-                fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
-                    self.visit_i128(v as i128)
+                    if <$type as Primitive>::from_str(v) == Some(V) {
+                        Ok($name::<V>)
+                    } else {
+                        Err(E::invalid_value(de::Unexpected::Str(v), &self))
+                    }
                 }
-                fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
+            }
+        };
+    };
+}
+
+declare_const! {
+    /// A const `u64` that is written as a decimal string, like the 64-bit IDs of APIs whose clients
+    /// can't represent them as JSON numbers.
+    ///
+    /// Deserialization fails unless the value is a string containing `V` in decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// use serde_constant::ConstU64Str;
+    /// type Id = ConstU64Str<1234567890123456789>;
+    /// assert_eq!(serde_json::to_string(&Id::default()).unwrap(), r#""1234567890123456789""#);
+    /// assert!(serde_json::from_str::<Id>(r#""1234567890123456789""#).is_ok());
+    /// assert!(serde_json::from_str::<Id>("1234567890123456789").is_err());
+    /// assert_eq!(
+    ///     serde_json::from_value::<Id>(json!("123")).unwrap_err().to_string(),
+    ///     r#"invalid value: string "123", expected the constant u64 1234567890123456789"#,
+    /// );
+    /// assert!(serde_json::from_value::<Id>(json!("snowflake")).is_err());
+    /// ```
+    pub struct ConstU64Str<const V: u64> => collect_str, deserialize_str;
+}
+
+declare_const! {
+    /// A const `i64` that is written as a decimal string. See [`ConstU64Str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json::json;
+    /// use serde_constant::ConstI64Str;
+    /// assert_eq!(serde_json::to_value(ConstI64Str::<-5>).unwrap(), json!("-5"));
+    /// assert!(serde_json::from_value::<ConstI64Str<-5>>(json!("-5")).is_ok());
+    /// assert!(serde_json::from_value::<ConstI64Str<-5>>(json!(-5)).is_err());
+    /// assert!(serde_json::from_value::<ConstI64Str<-5>>(json!("5")).is_err());
+    /// ```
+    pub struct ConstI64Str<const V: i64> => collect_str, deserialize_str;
+}
+
+/// Declares a named string constant, for stable Rust where `ConstStr` isn't available.
+///
+/// `const_string!(Circle = "circle");` declares a unit struct `Circle` that serializes as the
+/// string `"circle"`, and whose deserialization fails unless the string is `"circle"`, just like
+/// `ConstStr<"circle">`. Attributes and a visibility can be put before the name. Nothing is
+/// allocated, so this works without `alloc`.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// use serde_constant::{const_string, ConstValue};
+/// const_string!(
+///     /// The tag of a circle.
+///     pub Circle = "circle"
+/// );
+/// const_string!(Square = "square");
+///
+/// #[derive(Deserialize)]
+/// #[serde(untagged)]
+/// enum Shape {
+///     Circle { kind: Circle, radius: f64 },
+///     Square { kind: Square, side: f64 },
+/// }
+///
+/// assert!(matches!(
+///     serde_json::from_value(json!({ "kind": "square", "side": 1.0 })),
+///     Ok(Shape::Square { .. }),
+/// ));
+/// assert!(serde_json::from_value::<Shape>(json!({ "kind": "triangle", "side": 1.0 })).is_err());
+///
+/// assert_eq!(Circle::VALUE, "circle");
+/// assert_eq!(serde_json::to_value(Circle).unwrap(), json!("circle"));
+/// assert_eq!(
+///     serde_json::from_value::<Circle>(json!("square")).unwrap_err().to_string(),
+///     r#"invalid value: string "square", expected the constant str "circle""#,
+/// );
+/// ```
+#[macro_export]
+macro_rules! const_string {
+    ($(#[$attr:meta])* $vis:vis $name:ident = $value:literal $(;)?) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+        $vis struct $name;
+
+        const _: () = {
+            use $crate::__private::{
+                fmt,
+                serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+            };
+
+            impl $crate::ConstValue for $name {
+                type Repr = &'static str;
+                const VALUE: &'static str = $value;
+            }
+
+            impl ::core::hash::Hash for $name {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    $value.hash(state)
+                }
+            }
+
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&$value).finish()
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{:?}", $value)
+                }
+            }
+
+            impl Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
-                    E: de::Error,
+                    S: Serializer,
                 {
-                    self.visit_i128(v as i128)
+                    serializer.serialize_str($value)
                 }
-                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_str(Visitor)
+                }
+            }
+
+            struct Visitor;
+
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = $name;
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "the constant str {:?}", $value)
+                }
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
-                    self.visit_i128(v as i128)
+                    if v == $value {
+                        Ok($name)
+                    } else {
+                        Err(E::invalid_value(de::Unexpected::Str(v), &self))
+                    }
                 }
-                fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
                 where
                     E: de::Error,
                 {
-                    self.visit_i128(
-                        i128::try_from(v)
-                            .map_err(|_| E::invalid_value(Unexpected::Unsigned(v as u64), &Self))?,
+                    self.visit_str(v)
+                }
+            }
+        };
+    };
+}
+
+/// Declares a named constant that is a unit variant of an enum.
+///
+/// `const_variant!(Circle = Shape::Circle, 0);` declares a unit struct `Circle` that serializes
+/// with [`Serializer::serialize_unit_variant`], exactly like the variant `Circle` of an enum
+/// `Shape`, where it's the variant at index `0`. Deserialization fails unless the value is that
+/// variant, identified by either its name or its index, so formats that write enums by index, like
+/// `postcard`, work as well as those that write them by name. Attributes and a visibility can be
+/// put before the name.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::{const_variant, ConstValue};
+/// const_variant!(
+///     /// The kind of a circle.
+///     pub Circle = Shape::Circle, 0
+/// );
+/// const_variant!(Square = Shape::Square, 1);
+///
+/// assert_eq!(Circle::VALUE, "Circle");
+/// assert_eq!(Circle.to_string(), "Shape::Circle");
+///
+/// assert_eq!(serde_json::to_value(Circle).unwrap(), json!("Circle"));
+/// assert!(serde_json::from_value::<Circle>(json!("Circle")).is_ok());
+/// assert_eq!(
+///     serde_json::from_value::<Circle>(json!("Square")).unwrap_err().to_string(),
+///     "unknown variant `Square`, expected `Circle`",
+/// );
+///
+/// let bytes = postcard::to_allocvec(&Square).unwrap();
+/// assert_eq!(bytes, [1]);
+/// assert!(postcard::from_bytes::<Square>(&bytes).is_ok());
+/// assert!(postcard::from_bytes::<Circle>(&bytes).is_err());
+///
+/// // the same encoding as the enum itself
+/// #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+/// enum Shape {
+///     Circle,
+///     Square,
+/// }
+/// assert_eq!(postcard::from_bytes::<Shape>(&bytes).unwrap(), Shape::Square);
+/// assert_eq!(
+///     postcard::to_allocvec(&Shape::Circle).unwrap(),
+///     postcard::to_allocvec(&Circle).unwrap(),
+/// );
+/// ```
+#[macro_export]
+macro_rules! const_variant {
+    (
+        $(#[$attr:meta])* $vis:vis $name:ident = $enum:ident::$variant:ident, $index:literal $(;)?
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+        $vis struct $name;
+
+        const _: () = {
+            use $crate::__private::{
+                fmt,
+                serde::{Deserialize, Deserializer, Serialize, Serializer},
+            };
+
+            impl $crate::ConstValue for $name {
+                type Repr = &'static str;
+                const VALUE: &'static str = stringify!($variant);
+            }
+
+            impl ::core::hash::Hash for $name {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    stringify!($variant).hash(state)
+                }
+            }
+
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, concat!(stringify!($name), "({})"), self)
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(concat!(stringify!($enum), "::", stringify!($variant)))
+                }
+            }
+
+            impl Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.serialize_unit_variant(
+                        stringify!($enum),
+                        $index,
+                        stringify!($variant),
+                    )
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    $crate::__private::deserialize_unit_variant(
+                        deserializer,
+                        stringify!($enum),
+                        &[stringify!($variant)],
+                        $index,
                     )
+                    .map(|()| $name)
+                }
+            }
+        };
+    };
+}
+
+/// Declares a named `u8` constant made up of flags, for flags bytes that must always hold the same
+/// combination.
+///
+/// The generated unit struct has an associated constant for each flag, and its value is all of
+/// them combined. It (de)serializes exactly like the equivalent [`ConstU8`], and converts to and
+/// from it, but its [`Display`](fmt::Display) and mismatch errors list the flag names instead of
+/// a bare number.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::{const_flags, ConstU8, ConstValue};
+/// const_flags! {
+///     /// The flags byte of a v2 header.
+///     pub struct HeaderFlags {
+///         const COMPRESSED = 0b0000_0010;
+///         const ENCRYPTED = 0b0000_1000;
+///     }
+/// }
+///
+/// assert_eq!(HeaderFlags::VALUE, 0b0000_1010);
+/// assert_eq!(HeaderFlags::COMPRESSED, 0b0000_0010);
+/// assert_eq!(HeaderFlags.to_string(), "COMPRESSED | ENCRYPTED");
+/// assert_eq!(ConstU8::<0b0000_1010>::from(HeaderFlags), 10);
+///
+/// assert_eq!(serde_json::to_value(HeaderFlags).unwrap(), json!(10));
+/// assert!(serde_json::from_value::<HeaderFlags>(json!(10)).is_ok());
+/// # #[cfg(not(feature = "alloc"))]
+/// assert_eq!(
+///     serde_json::from_value::<HeaderFlags>(json!(2)).unwrap_err().to_string(),
+///     "invalid value: integer `2`, expected the constant flags COMPRESSED | ENCRYPTED (0b00001010)",
+/// );
+/// ```
+#[macro_export]
+macro_rules! const_flags {
+    (
+        $(#[$attr:meta])* $vis:vis struct $name:ident {
+            $($(#[$flag_attr:meta])* const $flag:ident = $bits:expr;)+
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+        $vis struct $name;
+
+        #[allow(dead_code)]
+        impl $name {
+            $(
+                $(#[$flag_attr])*
+                pub const $flag: u8 = $bits;
+            )+
+        }
+
+        const _: () = {
+            use $crate::__private::{
+                fmt,
+                serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+            };
+
+            const VALUE: u8 = 0 $(| $bits)+;
+
+            impl $crate::ConstValue for $name {
+                type Repr = u8;
+                const VALUE: u8 = VALUE;
+            }
+
+            impl ::core::hash::Hash for $name {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    VALUE.hash(state)
+                }
+            }
+
+            impl From<$name> for $crate::ConstU8<VALUE> {
+                fn from(_: $name) -> Self {
+                    $crate::ConstU8
                 }
             }
-        )*
-    };
-}
 
-declare_int!(
-    i8 => ConstI8 ConstI8Visitor serialize_i8 deserialize_i8,
-    i16 => ConstI16 ConstI16Visitor serialize_i16 deserialize_i16,
-    i32 => ConstI32 ConstI32Visitor serialize_i32 deserialize_i32,
-    i64 => ConstI64 ConstI64Visitor serialize_i64 deserialize_i64,
-    i128 => ConstI128 ConstI128Visitor serialize_i128 deserialize_i128,
-);
+            impl From<$crate::ConstU8<VALUE>> for $name {
+                fn from(_: $crate::ConstU8<VALUE>) -> Self {
+                    $name
+                }
+            }
 
-macro_rules! declare_uint {
-    ($($type:ty => $struct:ident $visitor:ident $ser_func:ident $deser_func:ident),* $(,)?) => {
-        $(
-            #[doc = concat!("A const `", stringify!($type), "`.")]
-            ///
-            ///  Deserialization fails if the value is not `V`.
-            #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
-            pub struct $struct<const V: $type>;
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, concat!(stringify!($name), "({})"), self)
+                }
+            }
 
-            impl<const V: $type> Serialize for $struct<V> {
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(concat!($(" | ", stringify!($flag)),+).trim_start_matches(" | "))
+                }
+            }
+
+            impl Serialize for $name {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
                     S: Serializer,
                 {
-                    serializer.$ser_func(V)
+                    serializer.serialize_u8(VALUE)
                 }
             }
 
-            impl<'de, const V: $type> Deserialize<'de> for $struct<V> {
+            impl<'de> Deserialize<'de> for $name {
                 fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
                 where
                     D: Deserializer<'de>,
                 {
-                    deserializer.$deser_func($visitor::<V>)
+                    $crate::__private::deserialize_u8(deserializer, VALUE, &Expecting, &[])
+                        .map(|()| $name)
                 }
             }
 
-            struct $visitor<const V: $type>;
-
-            impl<'de, const V: $type> Visitor<'de> for $visitor<V> {
-                type Value = $struct<V>;
-                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                    write!(formatter, "{V}")
-                }
-                fn visit_i8<E>(self, v: i8) -> Result<Self::Value, E>
+            impl<'de> $crate::DeserializeAny<'de> for $name {
+                fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
                 where
-                    E: de::Error,
+                    D: Deserializer<'de>,
                 {
-                    self.visit_i128(v as i128)
+                    $crate::__private::deserialize_any_u8(deserializer, VALUE, &Expecting, &[])
+                        .map(|()| $name)
                 }
-                fn visit_i16<E>(self, v: i16) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    self.visit_i128(v as i128)
+            }
+
+            struct Expecting;
+
+            impl de::Expected for Expecting {
+                fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "the constant flags {} ({:#010b})", $name, VALUE)
                 }
-                fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    self.visit_i128(v as i128)
+            }
+        };
+    };
+}
+
+/// Declares named constants for the discriminants of a fieldless `#[repr(u8)]` enum.
+///
+/// `const_enum_repr!(Color { pub ConstRed => Red = 0, pub ConstGreen => Green = 1 })` declares unit
+/// structs `ConstRed` and `ConstGreen` that serialize like [`ConstU8<0>`](ConstU8) and
+/// [`ConstU8<1>`](ConstU8), and display as `Color::Red` and `Color::Green`. Deserialization fails
+/// unless the value is that discriminant, and the error names both the expected variant and, if it
+/// is another one from the table, the variant that was found. A discriminant can be any constant
+/// expression, so an existing enum can be reused with `Red = Color::Red as u8`. Attributes and a
+/// visibility can be put before each name.
+///
+/// # Examples
+///
+/// ```
+/// # use serde_json::json;
+/// use serde_constant::{const_enum_repr, ConstU8, ConstValue};
+/// #[derive(Clone, Copy)]
+/// #[repr(u8)]
+/// enum Color {
+///     Red = 0,
+///     Green = 1,
+///     Blue = 4,
+/// }
+///
+/// const_enum_repr!(Color {
+///     /// A red pixel.
+///     pub ConstRed => Red = Color::Red as u8,
+///     pub ConstGreen => Green = 1,
+///     ConstBlue => Blue = 4,
+/// });
+///
+/// assert_eq!(ConstGreen::VALUE, Color::Green as u8);
+/// assert_eq!(ConstBlue.to_string(), "Color::Blue");
+/// assert_eq!(format!("{:?}", ConstRed), "ConstRed(Color::Red)");
+/// assert_eq!(ConstU8::<1>::from(ConstGreen), 1);
+///
+/// assert_eq!(serde_json::to_value(ConstBlue).unwrap(), json!(4));
+/// assert!(serde_json::from_value::<ConstRed>(json!(0)).is_ok());
+/// # #[cfg(not(feature = "alloc"))]
+/// assert_eq!(
+///     serde_json::from_value::<ConstRed>(json!(1)).unwrap_err().to_string(),
+///     "invalid value: Color::Green (1), expected the constant Color::Red (0)",
+/// );
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(
+///     serde_json::from_value::<ConstRed>(json!(1)).unwrap_err().to_string(),
+///     "expected the constant Color::Red (0), found Color::Green (1)",
+/// );
+/// // values outside the table are reported as plain integers
+/// # #[cfg(not(feature = "alloc"))]
+/// assert_eq!(
+///     serde_json::from_value::<ConstRed>(json!(2)).unwrap_err().to_string(),
+///     "invalid value: integer `2`, expected the constant Color::Red (0)",
+/// );
+///
+/// let bytes = postcard::to_allocvec(&ConstGreen).unwrap();
+/// assert_eq!(bytes, [1]);
+/// assert!(postcard::from_bytes::<ConstGreen>(&bytes).is_ok());
+/// assert!(postcard::from_bytes::<ConstBlue>(&bytes).is_err());
+/// ```
+#[macro_export]
+macro_rules! const_enum_repr {
+    (
+        $enum:ident {
+            $($(#[$attr:meta])* $vis:vis $name:ident => $variant:ident = $disc:expr),+ $(,)?
+        }
+    ) => {
+        $(
+            $(#[$attr])*
+            #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+            $vis struct $name;
+        )+
+
+        const _: () = {
+            use $crate::__private::{
+                fmt,
+                serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+            };
+
+            const VARIANTS: &[(&str, u8)] = &[
+                $((concat!(stringify!($enum), "::", stringify!($variant)), $disc)),+
+            ];
+
+            struct Expecting(&'static str, u8);
+
+            impl de::Expected for Expecting {
+                fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "the constant {} ({})", self.0, self.1)
                 }
-                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    self.visit_i128(v as i128)
+            }
+
+            $(
+                impl $crate::ConstValue for $name {
+                    type Repr = u8;
+                    const VALUE: u8 = $disc;
                 }
-                fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    if v < 0 {
-                        Err(E::invalid_value(Unexpected::Signed(v as i64), &self))
-                    } else {
-                        self.visit_u128(v as u128)
+
+                impl ::core::hash::Hash for $name {
+                    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        <$name as $crate::ConstValue>::VALUE.hash(state)
                     }
                 }
-                fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    self.visit_u128(v as u128)
+
+                impl From<$name> for $crate::ConstU8<{ $disc }> {
+                    fn from(_: $name) -> Self {
+                        $crate::ConstU8
+                    }
                 }
-                fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    self.visit_u128(v as u128)
+
+                impl From<$crate::ConstU8<{ $disc }>> for $name {
+                    fn from(_: $crate::ConstU8<{ $disc }>) -> Self {
+                        $name
+                    }
                 }
-                fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    self.visit_u128(v as u128)
+
+                impl fmt::Debug for $name {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(f, concat!(stringify!($name), "({})"), self)
+                    }
                 }
-                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    self.visit_u128(v as u128)
+
+                impl fmt::Display for $name {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.write_str(concat!(stringify!($enum), "::", stringify!($variant)))
+                    }
                 }
-                fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
-                where
-                    E: de::Error,
-                {
-                    if v == V as u128 {
-                        Ok($struct::<V>)
-                    } else {
-                        Err(E::invalid_value(Unexpected::Unsigned(v as u64), &self))
+
+                impl Serialize for $name {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        serializer.serialize_u8(<$name as $crate::ConstValue>::VALUE)
+                    }
+                }
+
+                impl<'de> Deserialize<'de> for $name {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        $crate::__private::deserialize_u8(
+                            deserializer,
+                            <$name as $crate::ConstValue>::VALUE,
+                            &Expecting(
+                                concat!(stringify!($enum), "::", stringify!($variant)),
+                                <$name as $crate::ConstValue>::VALUE,
+                            ),
+                            VARIANTS,
+                        )
+                        .map(|()| $name)
+                    }
+                }
+
+                impl<'de> $crate::DeserializeAny<'de> for $name {
+                    fn deserialize_any<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        $crate::__private::deserialize_any_u8(
+                            deserializer,
+                            <$name as $crate::ConstValue>::VALUE,
+                            &Expecting(
+                                concat!(stringify!($enum), "::", stringify!($variant)),
+                                <$name as $crate::ConstValue>::VALUE,
+                            ),
+                            VARIANTS,
+                        )
+                        .map(|()| $name)
                     }
                 }
+            )+
+        };
+    };
+}
+
+/// Declares an untagged enum whose struct variants are told apart by a constant tag field.
+///
+/// `const_match! { enum Msg { 1 => Ping {}, 2 => Pong { seq: u32 } } }` expands to an enum marked
+/// `#[serde(untagged)]`, where `Ping` is `Ping { tag: ConstI64<1> }` and `Pong` is
+/// `Pong { tag: ConstI64<2>, seq: u32 }`. The tag is the first field of every variant, so it is
+/// checked before the rest. Like a hand-written enum, it still needs to derive `Serialize` and
+/// `Deserialize` itself.
+///
+/// The tag values are only written once, so the expansion also dispatches on them: `TAGS` lists
+/// them in order, the `tag` method returns the one of a variant, and `variant_name` returns the
+/// variant that has a given tag value.
+///
+/// The tag field is named `tag` and holds a [`ConstI64`] by default. Another name and constant type
+/// can be given after the enum name, e.g. `enum Msg(kind: ConstStr)` for string tags, as long as
+/// the type is a constant from this crate taking a single value. Attributes and a visibility can be
+/// put before `enum`, and attributes before each variant. Giving two variants the same tag value is
+/// a compile error. To keep the tag out of the variants instead, derive `ConstTagged` with the
+/// `derive` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// use serde_constant::{const_match, ConstI64};
+/// const_match! {
+///     #[derive(Serialize, Deserialize, Debug, PartialEq)]
+///     pub enum Msg {
+///         1 => Ping {},
+///         /// A reply to a ping.
+///         2 => Pong { seq: u32 },
+///         -1 => Error { code: u16, reason: Option<String> },
+///     }
+/// }
+///
+/// // the expansion, written out
+/// let pong = Msg::Pong { tag: ConstI64::<2>, seq: 7 };
+/// let Msg::Pong { tag, seq: _ } = &pong else { unreachable!() };
+/// let _: &ConstI64<2> = tag;
+///
+/// assert_eq!(Msg::TAGS, [1, 2, -1]);
+/// assert_eq!(pong.tag(), 2);
+/// assert_eq!(Msg::Error { tag: ConstI64, code: 500, reason: None }.tag(), -1);
+/// assert_eq!(Msg::variant_name(2), Some("Pong"));
+/// assert_eq!(Msg::variant_name(3), None);
+///
+/// assert_eq!(serde_json::from_value::<Msg>(json!({ "tag": 1 })).unwrap(), Msg::Ping { tag: ConstI64 });
+/// assert_eq!(serde_json::from_value::<Msg>(json!({ "tag": 2, "seq": 7 })).unwrap(), pong);
+/// assert_eq!(serde_json::to_value(&pong).unwrap(), json!({ "tag": 2, "seq": 7 }));
+/// assert!(matches!(
+///     serde_json::from_value(json!({ "tag": -1, "code": 500, "reason": null })).unwrap(),
+///     Msg::Error { code: 500, reason: None, .. },
+/// ));
+/// // tags that no variant has are rejected
+/// assert!(serde_json::from_value::<Msg>(json!({ "tag": 3, "seq": 7 })).is_err());
+/// ```
+///
+/// With the `nightly` feature, tags can be strings:
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// use serde_constant::{const_match, ConstStr};
+/// const_match! {
+///     #[derive(Deserialize, Debug)]
+///     enum Principal(r#type: ConstStr) {
+///         "user" => User { name: String },
+///         "group" => Group { name: String, members: Vec<String> },
+///     }
+/// }
+///
+/// let group = serde_json::from_value::<Principal>(
+///     json!({ "type": "group", "name": "admins", "members": [] }),
+/// );
+/// assert_eq!(group.unwrap().tag(), "group");
+/// assert_eq!(Principal::variant_name("user"), Some("User"));
+/// assert!(serde_json::from_value::<Principal>(json!({ "type": "robot", "name": "r2d2" })).is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! const_match {
+    (
+        $(#[$attr:meta])* $vis:vis enum $enum:ident {
+            $($body:tt)*
+        }
+    ) => {
+        $crate::const_match! {
+            $(#[$attr])* $vis enum $enum(tag: ConstI64) {
+                $($body)*
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])* $vis:vis enum $enum:ident($field:ident: $tag:ident) {
+            $($(#[$vattr:meta])* $value:literal => $variant:ident { $($fields:tt)* }),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[serde(untagged)]
+        $vis enum $enum {
+            $(
+                $(#[$vattr])*
+                $variant {
+                    $field: $crate::$tag<{ $value }>,
+                    $($fields)*
+                },
+            )+
+        }
+
+        #[allow(dead_code)]
+        impl $enum {
+            /// The tag values of the variants, in order.
+            $vis const TAGS: &'static [$crate::__const_match_repr!($tag; $($value),+)] = &[
+                $(<$crate::$tag<{ $value }> as $crate::ConstValue>::VALUE,)+
+            ];
+
+            /// Returns the tag value of this variant.
+            $vis fn tag(&self) -> $crate::__const_match_repr!($tag; $($value),+) {
+                match self {
+                    $(
+                        $enum::$variant { .. } => {
+                            <$crate::$tag<{ $value }> as $crate::ConstValue>::VALUE
+                        }
+                    )+
+                }
             }
-        )*
+
+            /// Returns the name of the variant with the tag value `tag`, or `None` if no variant
+            /// has it.
+            // untagged enums take the first variant that fits, so a repeated tag would shadow a
+            // variant, and shows up here as an unreachable pattern
+            #[deny(unreachable_patterns)]
+            $vis fn variant_name(
+                tag: $crate::__const_match_repr!($tag; $($value),+),
+            ) -> ::core::option::Option<&'static str> {
+                match tag {
+                    $($value => ::core::option::Option::Some(stringify!($variant)),)+
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
     };
 }
 
-declare_uint!(
-    u8 => ConstU8 ConstU8Visitor serialize_u8 deserialize_u8,
-    u16 => ConstU16 ConstU16Visitor serialize_u16 deserialize_u16,
-    u32 => ConstU32 ConstU32Visitor serialize_u32 deserialize_u32,
-    u64 => ConstU64 ConstU64Visitor serialize_u64 deserialize_u64,
-    u128 => ConstU128 ConstU128Visitor serialize_u128 deserialize_u128,
-);
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_match_repr {
+    ($tag:ident; $value:literal $(, $rest:literal)*) => {
+        <$crate::$tag<{ $value }> as $crate::ConstValue>::Repr
+    };
+}
 
-/// A const `char`.
-///
-/// Deserialization fails if the value is not `V`.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
-pub struct ConstChar<const V: char>;
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_const_serialize {
+    ($serializer:ident.collect_str($value:expr)) => {
+        $serializer.collect_str(&$value)
+    };
+    ($serializer:ident.$ser_func:ident($value:expr)) => {
+        $serializer.$ser_func($value as _)
+    };
+}
 
-impl<const V: char> Serialize for ConstChar<V> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+#[doc(hidden)]
+pub mod __private {
+    pub use core::fmt;
+    pub use serde;
+    use serde::de::{self, Deserializer, Expected, Visitor};
+
+    pub fn invalid_i128<E: de::Error>(v: i128, exp: &dyn Expected) -> E {
+        crate::invalid_i128(v, exp)
+    }
+
+    pub fn invalid_u128<E: de::Error>(v: u128, exp: &dyn Expected) -> E {
+        crate::invalid_u128(v, exp)
+    }
+
+    /// Deserializes a `u8` the way [`ConstU8`](crate::ConstU8) does, failing with `exp` unless
+    /// it is `value`. Values found in `variants` are named in the error. Used by `const_flags!`
+    /// and `const_enum_repr!`.
+    pub fn deserialize_u8<'de, D>(
+        deserializer: D,
+        value: u8,
+        exp: &dyn Expected,
+        variants: &[(&'static str, u8)],
+    ) -> Result<(), D::Error>
     where
-        S: Serializer,
+        D: Deserializer<'de>,
     {
-        serializer.serialize_char(V)
+        // hinted deserialize calls never hand strings to the visitor
+        #[cfg(any(feature = "lenient-strings", feature = "char-bytes"))]
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_any(U8Visitor {
+                value,
+                exp,
+                variants,
+            });
+        }
+        deserializer.deserialize_u8(U8Visitor {
+            value,
+            exp,
+            variants,
+        })
     }
-}
 
-impl<'de, const V: char> Deserialize<'de> for ConstChar<V> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    /// Like [`deserialize_u8`], but driven by `deserialize_any`.
+    pub fn deserialize_any_u8<'de, D>(
+        deserializer: D,
+        value: u8,
+        exp: &dyn Expected,
+        variants: &[(&'static str, u8)],
+    ) -> Result<(), D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_char(ConstCharVisitor::<V>)
+        deserializer.deserialize_any(U8Visitor {
+            value,
+            exp,
+            variants,
+        })
     }
-}
 
-struct ConstCharVisitor<const V: char>;
+    struct U8Visitor<'a> {
+        value: u8,
+        exp: &'a dyn Expected,
+        variants: &'a [(&'static str, u8)],
+    }
 
-impl<'de, const V: char> Visitor<'de> for ConstCharVisitor<V> {
-    type Value = ConstChar<V>;
-    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "'{V}'")
+    impl<'de> Visitor<'de> for U8Visitor<'_> {
+        type Value = ();
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            self.exp.fmt(formatter)
+        }
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.visit_i128(v as i128)
+        }
+        fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v < 0 {
+                Err(crate::invalid_i128(v, self.exp))
+            } else {
+                self.visit_u128(v as u128)
+            }
+        }
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.visit_u128(v as u128)
+        }
+        fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v != self.value as u128 {
+                if let Some((name, _)) = self.variants.iter().find(|&&(_, d)| d as u128 == v) {
+                    return Err(crate::invalid_variant(v, name, self.exp));
+                }
+            }
+            crate::check_u128(v, self.value as u128, self.exp)
+        }
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            // whole numbers only match with `accept-whole-floats`, and other floats are the wrong
+            // type entirely
+            #[cfg(feature = "accept-whole-floats")]
+            return match crate::f64_to_u128(v) {
+                Some(v) => self.visit_u128(v),
+                None => Err(E::invalid_value(de::Unexpected::Float(v), self.exp)),
+            };
+            #[cfg(not(feature = "accept-whole-floats"))]
+            Err(E::invalid_type(de::Unexpected::Float(v), self.exp))
+        }
+        #[cfg(any(feature = "lenient-strings", feature = "char-bytes"))]
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            #[cfg(feature = "lenient-strings")]
+            if let Ok(parsed) = v.parse::<u128>() {
+                return self.visit_u128(parsed);
+            }
+            #[cfg(feature = "char-bytes")]
+            if v.as_bytes() == [self.value] {
+                return Ok(());
+            }
+            Err(E::invalid_value(de::Unexpected::Str(v), self.exp))
+        }
     }
-    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+
+    /// Deserializes the unit variant `variants[0]` of the enum `name`, identified by either its
+    /// name or its `index`. Used by `const_variant!`.
+    pub fn deserialize_unit_variant<'de, D>(
+        deserializer: D,
+        name: &'static str,
+        variants: &'static [&'static str],
+        index: u32,
+    ) -> Result<(), D::Error>
     where
-        E: de::Error,
+        D: Deserializer<'de>,
     {
-        if v == V {
-            Ok(ConstChar::<V>)
-        } else {
-            Err(E::invalid_value(Unexpected::Char(v), &self))
+        let visitor = UnitVariantVisitor {
+            name,
+            variants,
+            index,
+        };
+        deserializer.deserialize_enum(name, variants, visitor)
+    }
+
+    #[derive(Copy, Clone)]
+    struct UnitVariantVisitor {
+        name: &'static str,
+        variants: &'static [&'static str],
+        index: u32,
+    }
+
+    impl<'de> Visitor<'de> for UnitVariantVisitor {
+        type Value = ();
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "the constant variant {}::{}",
+                self.name, self.variants[0]
+            )
+        }
+        fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::EnumAccess<'de>,
+        {
+            let ((), variant) = data.variant_seed(VariantIdentifier(self))?;
+            de::VariantAccess::unit_variant(variant)
         }
     }
-    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-    where
-        E: de::Error,
-    {
-        let mut chs = v.chars();
-        let ch = chs.next().ok_or_else(|| E::invalid_length(0, &self))?;
-        if chs.next().is_some() {
-            Err(E::invalid_length(2, &self))
-        } else {
-            self.visit_char(ch)
+
+    /// Matches the variant's name or index.
+    struct VariantIdentifier(UnitVariantVisitor);
+
+    impl<'de> de::DeserializeSeed<'de> for VariantIdentifier {
+        type Value = ();
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for VariantIdentifier {
+        type Value = ();
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "variant `{}` or its index {}",
+                self.0.variants[0], self.0.index
+            )
+        }
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v == self.0.index as u64 {
+                Ok(())
+            } else {
+                Err(E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+        }
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v == self.0.variants[0] {
+                Ok(())
+            } else {
+                Err(E::unknown_variant(v, self.0.variants))
+            }
+        }
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match core::str::from_utf8(v) {
+                Ok(v) => self.visit_str(v),
+                Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(v), &self)),
+            }
+        }
+    }
+
+    /// Lossless conversions into the types `declare_const!` supports.
+    pub trait Primitive: Sized {
+        fn from_bool(_: bool) -> Option<Self> {
+            None
+        }
+        fn from_i128(_: i128) -> Option<Self> {
+            None
+        }
+        fn from_u128(_: u128) -> Option<Self> {
+            None
         }
+        fn from_char(_: char) -> Option<Self> {
+            None
+        }
+        fn from_str(v: &str) -> Option<Self>;
+    }
+
+    impl Primitive for bool {
+        fn from_bool(v: bool) -> Option<Self> {
+            Some(v)
+        }
+        fn from_str(v: &str) -> Option<Self> {
+            v.parse().ok()
+        }
+    }
+
+    impl Primitive for char {
+        fn from_char(v: char) -> Option<Self> {
+            Some(v)
+        }
+        fn from_str(v: &str) -> Option<Self> {
+            v.parse().ok()
+        }
+    }
+
+    macro_rules! impl_primitive_int {
+        ($($type:ty),*) => {
+            $(
+                impl Primitive for $type {
+                    fn from_i128(v: i128) -> Option<Self> {
+                        v.try_into().ok()
+                    }
+                    fn from_u128(v: u128) -> Option<Self> {
+                        v.try_into().ok()
+                    }
+                    fn from_str(v: &str) -> Option<Self> {
+                        v.parse().ok()
+                    }
+                }
+            )*
+        };
     }
+
+    impl_primitive_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
 }
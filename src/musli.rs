@@ -0,0 +1,338 @@
+//! [`Encode`] and [`Decode`] implementations, enabled by the `musli` feature.
+//!
+//! Each constant encodes as its underlying value, and decoding fails unless the value is the
+//! constant, with the [`ConstMismatch`] as the error message.
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//! use serde_constant::{ConstChar, ConstEmptySeq, ConstEmptyStr, ConstI64, ConstU128};
+//!
+//! #[derive(Encode, Decode, Debug, PartialEq)]
+//! struct Message {
+//!     version: ConstI64<2>,
+//!     kind: ConstChar<'m'>,
+//!     id: ConstU128<{ u128::MAX }>,
+//! }
+//!
+//! let message = Message {
+//!     version: ConstI64::<2>,
+//!     kind: ConstChar::<'m'>,
+//!     id: ConstU128::<{ u128::MAX }>,
+//! };
+//! let bytes = musli::storage::to_vec(&message).unwrap();
+//! assert_eq!(musli::storage::from_slice::<Message>(&bytes).unwrap(), message);
+//!
+//! let bytes = musli::storage::to_vec(&3i64).unwrap();
+//! let err = musli::storage::from_slice::<ConstI64<2>>(&bytes).unwrap_err();
+//! assert!(err.to_string().contains("expected 2, found 3"));
+//!
+//! let bytes = musli::storage::to_vec(&'n').unwrap();
+//! assert!(musli::storage::from_slice::<ConstChar<'m'>>(&bytes).is_err());
+//!
+//! let bytes = musli::storage::to_vec(&ConstEmptySeq).unwrap();
+//! assert_eq!(bytes, musli::storage::to_vec(&Vec::<u8>::new()).unwrap());
+//! assert!(musli::storage::from_slice::<ConstEmptySeq>(&bytes).is_ok());
+//! let bytes = musli::storage::to_vec(&vec![1u8]).unwrap();
+//! assert!(musli::storage::from_slice::<ConstEmptySeq>(&bytes).is_err());
+//! let bytes = musli::storage::to_vec("x").unwrap();
+//! assert!(musli::storage::from_slice::<ConstEmptyStr>(&bytes).is_err());
+//! ```
+use crate::*;
+use ::musli::{Allocator, Context, Decode, Decoder, Encode, Encoder};
+
+macro_rules! impl_musli {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<M, const V: $type> Encode<M> for $struct<V> {
+                type Encode = Self;
+                fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+                where
+                    E: Encoder<Mode = M>,
+                {
+                    encoder.encode(<$struct<V> as ConstValue>::VALUE)
+                }
+                fn as_encode(&self) -> &Self::Encode {
+                    self
+                }
+            }
+
+            impl<'de, M, A: Allocator, const V: $type> Decode<'de, M, A> for $struct<V> {
+                fn decode<D>(decoder: D) -> Result<Self, D::Error>
+                where
+                    D: Decoder<'de, Mode = M, Allocator = A>,
+                {
+                    let cx = decoder.cx();
+                    let value = decoder.decode::<<$struct<V> as ConstValue>::Repr>()?;
+                    $struct::<V>::try_from(value).map_err(|err| cx.message(err))
+                }
+            }
+        )*
+    };
+}
+
+impl_musli!(
+    ConstBool<bool>,
+    ConstI8<i8>,
+    ConstI16<i16>,
+    ConstI32<i32>,
+    ConstI64<i64>,
+    ConstI128<i128>,
+    ConstIsize<isize>,
+    ConstU8<u8>,
+    ConstU16<u16>,
+    ConstU32<u32>,
+    ConstU64<u64>,
+    ConstU128<u128>,
+    ConstUsize<usize>,
+    ConstNonZeroI8<i8>,
+    ConstNonZeroI16<i16>,
+    ConstNonZeroI32<i32>,
+    ConstNonZeroI64<i64>,
+    ConstNonZeroI128<i128>,
+    ConstNonZeroIsize<isize>,
+    ConstNonZeroU8<u8>,
+    ConstNonZeroU16<u16>,
+    ConstNonZeroU32<u32>,
+    ConstNonZeroU64<u64>,
+    ConstNonZeroU128<u128>,
+    ConstNonZeroUsize<usize>,
+    ConstChar<char>,
+    ConstCharCi<char>,
+    ConstPrintableChar<char>,
+    ConstScalarU32<u32>,
+    ConstSafeU64<u64>,
+    ConstF32<u32>,
+    ConstF64<u64>,
+    ConstInfinityF64<bool>,
+);
+
+#[cfg(feature = "nightly")]
+impl<M, const V: &'static str> Encode<M> for ConstStr<V> {
+    type Encode = Self;
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_string(V)
+    }
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, M, A: Allocator, const V: &'static str> Decode<'de, M, A> for ConstStr<V> {
+    fn decode<D>(decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        let cx = decoder.cx();
+        decoder.decode_unsized(|value: &str| {
+            ConstStr::<V>::try_from(value).map_err(|err| cx.message(err))
+        })
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<M, const V: &'static str> Encode<M> for ConstUnitVariant<V> {
+    type Encode = Self;
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_unit_variant(V)
+    }
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, M, A: Allocator, const V: &'static str> Decode<'de, M, A> for ConstUnitVariant<V> {
+    fn decode<D>(decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        use ::musli::de::VariantDecoder;
+        let cx = decoder.cx();
+        decoder.decode_variant(|variant| {
+            variant.decode_tag()?.decode_unsized(|tag: &str| {
+                ConstUnitVariant::<V>::try_from(tag).map_err(|err| cx.message(err))
+            })?;
+            variant.decode_value()?.decode_empty()?;
+            Ok(ConstUnitVariant::<V>)
+        })
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<M, const V: &'static [u8]> Encode<M> for ConstBytes<V> {
+    type Encode = Self;
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_bytes(V)
+    }
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, M, A: Allocator, const V: &'static [u8]> Decode<'de, M, A> for ConstBytes<V> {
+    fn decode<D>(decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        let cx = decoder.cx();
+        decoder.decode_unsized_bytes(|value: &[u8]| {
+            // `[u8]` has no `Display`, so report the mismatch the way `ConstBytes` displays
+            if value == V {
+                Ok(ConstBytes::<V>)
+            } else {
+                Err(cx.message(format_args!(
+                    "expected {}, found b\"{}\"",
+                    ConstBytes::<V>,
+                    value.escape_ascii()
+                )))
+            }
+        })
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<M, const V: i64, const NAME: &'static str> Encode<M> for ConstLabeled<V, NAME> {
+    type Encode = Self;
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode(V)
+    }
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, M, A: Allocator, const V: i64, const NAME: &'static str> Decode<'de, M, A>
+    for ConstLabeled<V, NAME>
+{
+    fn decode<D>(decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        let cx = decoder.cx();
+        let value = decoder.decode::<i64>()?;
+        ConstLabeled::<V, NAME>::try_from(value)
+            .map_err(|err| cx.message(format_args!("{err} for field {NAME:?}")))
+    }
+}
+
+impl<M> Encode<M> for ConstUnit {
+    type Encode = Self;
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_empty()
+    }
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+impl<'de, M, A: Allocator> Decode<'de, M, A> for ConstUnit {
+    fn decode<D>(decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        decoder.decode_empty().map(|()| ConstUnit)
+    }
+}
+
+impl<M> Encode<M> for ConstNanF64 {
+    type Encode = Self;
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_f64(f64::NAN)
+    }
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+impl<'de, M, A: Allocator> Decode<'de, M, A> for ConstNanF64 {
+    fn decode<D>(decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        let cx = decoder.cx();
+        let value = decoder.decode_f64()?;
+        ConstNanF64::try_from(value).map_err(|err| cx.message(err))
+    }
+}
+
+impl<M> Encode<M> for ConstEmptyStr {
+    type Encode = Self;
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_string("")
+    }
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+impl<'de, M, A: Allocator> Decode<'de, M, A> for ConstEmptyStr {
+    fn decode<D>(decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        let cx = decoder.cx();
+        decoder.decode_unsized(|value: &str| {
+            if value.is_empty() {
+                Ok(ConstEmptyStr)
+            } else {
+                Err(cx.message(format_args!("expected {ConstEmptyStr}, found {value:?}")))
+            }
+        })
+    }
+}
+
+impl<M> Encode<M> for ConstEmptySeq {
+    type Encode = Self;
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_sequence_fn(0, |_| Ok(()))
+    }
+    fn as_encode(&self) -> &Self::Encode {
+        self
+    }
+}
+
+impl<'de, M, A: Allocator> Decode<'de, M, A> for ConstEmptySeq {
+    fn decode<D>(decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        use ::musli::de::SequenceDecoder;
+        let cx = decoder.cx();
+        decoder.decode_sequence(|seq| {
+            if seq.try_decode_next()?.is_some() {
+                Err(cx.message(format_args!(
+                    "expected {ConstEmptySeq}, found a non-empty sequence"
+                )))
+            } else {
+                Ok(ConstEmptySeq)
+            }
+        })
+    }
+}
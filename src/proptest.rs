@@ -0,0 +1,99 @@
+//! [`Arbitrary`] implementations and strategies, enabled by the `proptest` feature.
+//!
+//! Each constant has exactly one value, so its strategy is always [`Just`] that value.
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use proptest_derive::Arbitrary;
+//! use serde_constant::ConstI64;
+//!
+//! #[derive(Arbitrary, Debug)]
+//! struct Message {
+//!     version: ConstI64<7>,
+//!     payload: u32,
+//! }
+//!
+//! proptest!(|(message: Message)| {
+//!     prop_assert_eq!(message.version, 7);
+//! });
+//! ```
+use crate::*;
+use ::proptest::{
+    arbitrary::Arbitrary,
+    strategy::{Just, Strategy},
+};
+
+/// Returns a strategy that always produces `T::default()`.
+///
+/// For the constant types, this is the only value.
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use serde_constant::{const_strategy, ConstBool};
+///
+/// proptest!(|(flag in const_strategy::<ConstBool<true>>())| {
+///     prop_assert!(flag == true);
+/// });
+/// ```
+pub fn const_strategy<T: Default + Clone + fmt::Debug>() -> impl Strategy<Value = T> {
+    Just(T::default())
+}
+
+macro_rules! impl_arbitrary {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> Arbitrary for $struct<V> {
+                type Parameters = ();
+                type Strategy = Just<Self>;
+                fn arbitrary_with(_: ()) -> Self::Strategy {
+                    Just($struct::<V>)
+                }
+            }
+        )*
+    };
+}
+
+with_const_types!(impl_arbitrary);
+
+impl Arbitrary for ConstUnit {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        Just(ConstUnit)
+    }
+}
+
+impl Arbitrary for ConstNanF64 {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        Just(ConstNanF64)
+    }
+}
+
+impl Arbitrary for ConstEmptyStr {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        Just(ConstEmptyStr)
+    }
+}
+
+impl Arbitrary for ConstEmptySeq {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        Just(ConstEmptySeq)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> Arbitrary for ConstLabeled<V, NAME> {
+    type Parameters = ();
+    type Strategy = Just<Self>;
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        Just(ConstLabeled::<V, NAME>)
+    }
+}
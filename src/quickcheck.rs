@@ -0,0 +1,82 @@
+//! [`Arbitrary`] implementations, enabled by the `quickcheck` feature.
+//!
+//! Each constant has exactly one value, so generating one ignores the generator, and there's
+//! nothing to shrink to.
+//!
+//! ```
+//! use quickcheck::{quickcheck, Arbitrary, Gen};
+//! use serde::{Deserialize, Serialize};
+//! use serde_constant::{ConstBool, ConstI64};
+//!
+//! #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+//! struct Message {
+//!     version: ConstI64<2>,
+//!     compressed: ConstBool<false>,
+//!     payload: Vec<u8>,
+//! }
+//!
+//! impl Arbitrary for Message {
+//!     fn arbitrary(g: &mut Gen) -> Self {
+//!         Message {
+//!             version: Arbitrary::arbitrary(g),
+//!             compressed: Arbitrary::arbitrary(g),
+//!             payload: Arbitrary::arbitrary(g),
+//!         }
+//!     }
+//! }
+//!
+//! fn roundtrip(message: Message) -> bool {
+//!     let json = serde_json::to_string(&message).unwrap();
+//!     serde_json::from_str::<Message>(&json).unwrap() == message
+//! }
+//!
+//! quickcheck(roundtrip as fn(Message) -> bool);
+//! assert_eq!(ConstI64::<2>::arbitrary(&mut Gen::new(10)).shrink().count(), 0);
+//! ```
+use crate::*;
+use ::quickcheck::{Arbitrary, Gen};
+
+macro_rules! impl_arbitrary {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> Arbitrary for $struct<V> {
+                fn arbitrary(_: &mut Gen) -> Self {
+                    $struct::<V>
+                }
+            }
+        )*
+    };
+}
+
+with_const_types!(impl_arbitrary);
+
+impl Arbitrary for ConstUnit {
+    fn arbitrary(_: &mut Gen) -> Self {
+        ConstUnit
+    }
+}
+
+impl Arbitrary for ConstNanF64 {
+    fn arbitrary(_: &mut Gen) -> Self {
+        ConstNanF64
+    }
+}
+
+impl Arbitrary for ConstEmptyStr {
+    fn arbitrary(_: &mut Gen) -> Self {
+        ConstEmptyStr
+    }
+}
+
+impl Arbitrary for ConstEmptySeq {
+    fn arbitrary(_: &mut Gen) -> Self {
+        ConstEmptySeq
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> Arbitrary for ConstLabeled<V, NAME> {
+    fn arbitrary(_: &mut Gen) -> Self {
+        ConstLabeled::<V, NAME>
+    }
+}
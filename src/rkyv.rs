@@ -0,0 +1,212 @@
+//! [`rkyv`](::rkyv) implementations, enabled by the `rkyv` feature.
+//!
+//! Each constant archives as itself, and takes up no space in the archive.
+//!
+//! ```
+//! use rkyv::rancor::Error;
+//! use serde_constant::ConstU16;
+//!
+//! #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize, Debug, PartialEq)]
+//! struct Packet {
+//!     id: u32,
+//!     version: ConstU16<42>,
+//! }
+//!
+//! let packet = Packet { id: 7, version: ConstU16 };
+//! let bytes = rkyv::to_bytes::<Error>(&packet).unwrap();
+//! let archived = rkyv::access::<ArchivedPacket, Error>(&bytes).unwrap();
+//! assert_eq!(archived.id, 7);
+//! assert_eq!(archived.version, 42);
+//! assert_eq!(rkyv::deserialize::<Packet, Error>(archived).unwrap(), packet);
+//! ```
+use crate::*;
+use ::rkyv::{
+    bytecheck::CheckBytes, rancor::Fallible, Archive, Deserialize, Place, Portable, Serialize,
+};
+
+macro_rules! impl_rkyv {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            // SAFETY: constants are zero-sized, so their layout is the same on all targets.
+            unsafe impl<const V: $type> Portable for $struct<V> {}
+
+            // SAFETY: constants are zero-sized, so all bit patterns are valid.
+            unsafe impl<const V: $type, C: Fallible + ?Sized> CheckBytes<C> for $struct<V> {
+                unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+                    Ok(())
+                }
+            }
+
+            impl<const V: $type> Archive for $struct<V> {
+                type Archived = $struct<V>;
+                type Resolver = ();
+                fn resolve(&self, _: Self::Resolver, _: Place<Self::Archived>) {}
+            }
+
+            impl<const V: $type, S: Fallible + ?Sized> Serialize<S> for $struct<V> {
+                fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                    Ok(())
+                }
+            }
+
+            impl<const V: $type, D: Fallible + ?Sized> Deserialize<$struct<V>, D> for $struct<V> {
+                fn deserialize(&self, _: &mut D) -> Result<$struct<V>, D::Error> {
+                    Ok($struct::<V>)
+                }
+            }
+        )*
+    };
+}
+
+with_const_types!(impl_rkyv);
+
+// SAFETY: `ConstUnit` is zero-sized, so its layout is the same on all targets.
+unsafe impl Portable for ConstUnit {}
+
+// SAFETY: `ConstUnit` is zero-sized, so all bit patterns are valid.
+unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for ConstUnit {
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl Archive for ConstUnit {
+    type Archived = ConstUnit;
+    type Resolver = ();
+    fn resolve(&self, _: Self::Resolver, _: Place<Self::Archived>) {}
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for ConstUnit {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<ConstUnit, D> for ConstUnit {
+    fn deserialize(&self, _: &mut D) -> Result<ConstUnit, D::Error> {
+        Ok(ConstUnit)
+    }
+}
+
+// SAFETY: `ConstNanF64` is zero-sized, so its layout is the same on all targets.
+unsafe impl Portable for ConstNanF64 {}
+
+// SAFETY: `ConstNanF64` is zero-sized, so all bit patterns are valid.
+unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for ConstNanF64 {
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl Archive for ConstNanF64 {
+    type Archived = ConstNanF64;
+    type Resolver = ();
+    fn resolve(&self, _: Self::Resolver, _: Place<Self::Archived>) {}
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for ConstNanF64 {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<ConstNanF64, D> for ConstNanF64 {
+    fn deserialize(&self, _: &mut D) -> Result<ConstNanF64, D::Error> {
+        Ok(ConstNanF64)
+    }
+}
+
+// SAFETY: `ConstEmptyStr` is zero-sized, so its layout is the same on all targets.
+unsafe impl Portable for ConstEmptyStr {}
+
+// SAFETY: `ConstEmptyStr` is zero-sized, so all bit patterns are valid.
+unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for ConstEmptyStr {
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl Archive for ConstEmptyStr {
+    type Archived = ConstEmptyStr;
+    type Resolver = ();
+    fn resolve(&self, _: Self::Resolver, _: Place<Self::Archived>) {}
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for ConstEmptyStr {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<ConstEmptyStr, D> for ConstEmptyStr {
+    fn deserialize(&self, _: &mut D) -> Result<ConstEmptyStr, D::Error> {
+        Ok(ConstEmptyStr)
+    }
+}
+
+// SAFETY: `ConstEmptySeq` is zero-sized, so its layout is the same on all targets.
+unsafe impl Portable for ConstEmptySeq {}
+
+// SAFETY: `ConstEmptySeq` is zero-sized, so all bit patterns are valid.
+unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for ConstEmptySeq {
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl Archive for ConstEmptySeq {
+    type Archived = ConstEmptySeq;
+    type Resolver = ();
+    fn resolve(&self, _: Self::Resolver, _: Place<Self::Archived>) {}
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for ConstEmptySeq {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<ConstEmptySeq, D> for ConstEmptySeq {
+    fn deserialize(&self, _: &mut D) -> Result<ConstEmptySeq, D::Error> {
+        Ok(ConstEmptySeq)
+    }
+}
+
+// SAFETY: `ConstLabeled` is zero-sized, so its layout is the same on all targets.
+#[cfg(feature = "nightly")]
+unsafe impl<const V: i64, const NAME: &'static str> Portable for ConstLabeled<V, NAME> {}
+
+// SAFETY: `ConstLabeled` is zero-sized, so all bit patterns are valid.
+#[cfg(feature = "nightly")]
+unsafe impl<const V: i64, const NAME: &'static str, C: Fallible + ?Sized> CheckBytes<C>
+    for ConstLabeled<V, NAME>
+{
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> Archive for ConstLabeled<V, NAME> {
+    type Archived = ConstLabeled<V, NAME>;
+    type Resolver = ();
+    fn resolve(&self, _: Self::Resolver, _: Place<Self::Archived>) {}
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str, S: Fallible + ?Sized> Serialize<S>
+    for ConstLabeled<V, NAME>
+{
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str, D: Fallible + ?Sized>
+    Deserialize<ConstLabeled<V, NAME>, D> for ConstLabeled<V, NAME>
+{
+    fn deserialize(&self, _: &mut D) -> Result<ConstLabeled<V, NAME>, D::Error> {
+        Ok(ConstLabeled::<V, NAME>)
+    }
+}
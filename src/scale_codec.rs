@@ -0,0 +1,109 @@
+//! SCALE codec implementations, enabled by the `scale-codec` feature.
+//!
+//! Constants are encoded as their underlying value, the same as the primitive would be, and
+//! decoding fails unless the value is the constant. This covers [`ConstBool`], [`ConstUnit`], and
+//! the fixed-width integer constants, since SCALE has no encoding for `char`s, floats, or
+//! `isize`/`usize`.
+//!
+//! ```
+//! use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+//! use serde_constant::ConstU16;
+//!
+//! #[derive(Encode, Decode, MaxEncodedLen, Debug, PartialEq)]
+//! struct Packet {
+//!     version: ConstU16<42>,
+//!     len: u32,
+//! }
+//!
+//! let packet = Packet { version: ConstU16, len: 7 };
+//! let bytes = packet.encode();
+//! assert_eq!(bytes, [42, 0, 7, 0, 0, 0]);
+//! assert_eq!(Packet::decode(&mut &bytes[..]).unwrap(), packet);
+//! assert!(Packet::decode(&mut &[43, 0, 7, 0, 0, 0][..]).is_err());
+//! assert_eq!(Packet::max_encoded_len(), 6);
+//! ```
+use crate::*;
+use ::parity_scale_codec::{
+    Decode, DecodeWithMemTracking, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output,
+};
+
+macro_rules! impl_scale_codec {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> Encode for $struct<V> {
+                fn size_hint(&self) -> usize {
+                    <$struct<V> as ConstValue>::VALUE.size_hint()
+                }
+                fn encode_to<W: Output + ?Sized>(&self, dest: &mut W) {
+                    <$struct<V> as ConstValue>::VALUE.encode_to(dest)
+                }
+            }
+
+            impl<const V: $type> EncodeLike for $struct<V> {}
+
+            impl<const V: $type> Decode for $struct<V> {
+                fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+                    // `NonZero` constants reject a zero `V` here
+                    let _ = <$struct<V> as ConstValue>::VALUE;
+                    if <$type>::decode(input)? == V {
+                        Ok($struct::<V>)
+                    } else {
+                        Err(concat!("value is not the constant ", stringify!($struct)).into())
+                    }
+                }
+            }
+
+            impl<const V: $type> DecodeWithMemTracking for $struct<V> {}
+
+            impl<const V: $type> MaxEncodedLen for $struct<V> {
+                fn max_encoded_len() -> usize {
+                    <$type>::max_encoded_len()
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_codec!(
+    ConstBool<bool>,
+    ConstI8<i8>,
+    ConstI16<i16>,
+    ConstI32<i32>,
+    ConstI64<i64>,
+    ConstI128<i128>,
+    ConstU8<u8>,
+    ConstU16<u16>,
+    ConstU32<u32>,
+    ConstU64<u64>,
+    ConstU128<u128>,
+    ConstNonZeroI8<i8>,
+    ConstNonZeroI16<i16>,
+    ConstNonZeroI32<i32>,
+    ConstNonZeroI64<i64>,
+    ConstNonZeroI128<i128>,
+    ConstNonZeroU8<u8>,
+    ConstNonZeroU16<u16>,
+    ConstNonZeroU32<u32>,
+    ConstNonZeroU64<u64>,
+    ConstNonZeroU128<u128>,
+);
+
+impl Encode for ConstUnit {
+    fn encode_to<W: Output + ?Sized>(&self, _: &mut W) {}
+}
+
+impl EncodeLike for ConstUnit {}
+
+impl Decode for ConstUnit {
+    fn decode<I: Input>(_: &mut I) -> Result<Self, Error> {
+        Ok(ConstUnit)
+    }
+}
+
+impl DecodeWithMemTracking for ConstUnit {}
+
+impl MaxEncodedLen for ConstUnit {
+    fn max_encoded_len() -> usize {
+        0
+    }
+}
@@ -0,0 +1,188 @@
+//! [`JsonSchema`] implementations, enabled by the `schemars` feature.
+//!
+//! Every constant produces a schema with the `const` keyword, using the same JSON value that the
+//! constant serializes to.
+//!
+//! ```
+//! use schemars::schema_for;
+//! use serde_constant::{ConstBool, ConstChar, ConstCharCi, ConstI64};
+//! use serde_json::json;
+//!
+//! assert_eq!(schema_for!(ConstI64<2>).get("const"), Some(&json!(2)));
+//! assert_eq!(schema_for!(ConstBool<true>).get("const"), Some(&json!(true)));
+//! assert_eq!(schema_for!(ConstChar<'c'>).get("const"), Some(&json!("c")));
+//! assert_eq!(schema_for!(ConstI64<2>).get("title"), Some(&json!("ConstI64<2>")));
+//!
+//! // case-insensitive characters list every spelling the deserializer accepts
+//! assert_eq!(schema_for!(ConstCharCi<'a'>).get("enum"), Some(&json!(["a", "A"])));
+//! assert_eq!(schema_for!(ConstCharCi<'Σ'>).get("enum"), Some(&json!(["Σ", "σ"])));
+//! ```
+use crate::*;
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use alloc::{borrow::Cow, format};
+
+/// Builds a schema matching only the serialized form of `value`.
+fn const_schema<T: Serialize>(value: T) -> Schema {
+    match serde_json::to_value(value) {
+        Ok(value) => json_schema!({ "const": value }),
+        // serde_json can't represent integers outside the 64-bit range
+        Err(_) => json_schema!({ "type": "integer" }),
+    }
+}
+
+macro_rules! impl_json_schema {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> JsonSchema for $struct<V> {
+                fn inline_schema() -> bool {
+                    true
+                }
+                fn schema_name() -> Cow<'static, str> {
+                    format!(concat!(stringify!($struct), "<{}>"), $struct::<V>).into()
+                }
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    const_schema($struct::<V>)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema!(
+    ConstBool<bool>,
+    ConstI8<i8>,
+    ConstI16<i16>,
+    ConstI32<i32>,
+    ConstI64<i64>,
+    ConstI128<i128>,
+    ConstIsize<isize>,
+    ConstU8<u8>,
+    ConstU16<u16>,
+    ConstU32<u32>,
+    ConstU64<u64>,
+    ConstU128<u128>,
+    ConstUsize<usize>,
+    ConstNonZeroI8<i8>,
+    ConstNonZeroI16<i16>,
+    ConstNonZeroI32<i32>,
+    ConstNonZeroI64<i64>,
+    ConstNonZeroI128<i128>,
+    ConstNonZeroIsize<isize>,
+    ConstNonZeroU8<u8>,
+    ConstNonZeroU16<u16>,
+    ConstNonZeroU32<u32>,
+    ConstNonZeroU64<u64>,
+    ConstNonZeroU128<u128>,
+    ConstNonZeroUsize<usize>,
+    ConstChar<char>,
+    ConstPrintableChar<char>,
+    ConstScalarU32<u32>,
+    ConstSafeU64<u64>,
+);
+
+#[cfg(feature = "nightly")]
+impl_json_schema!(
+    ConstStr<&'static str>,
+    ConstUnitVariant<&'static str>,
+    ConstBytes<&'static [u8]>,
+);
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> JsonSchema for ConstLabeled<V, NAME> {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        format!("ConstLabeled<{V}, {NAME:?}>").into()
+    }
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        const_schema(ConstLabeled::<V, NAME>)
+    }
+}
+
+impl<const V: char> JsonSchema for ConstCharCi<V> {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        format!("ConstCharCi<{}>", ConstCharCi::<V>).into()
+    }
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        // JSON Schema has no case-insensitive matching, so list the spellings
+        json_schema!({ "enum": ConstCharCi::<V>::spellings() })
+    }
+}
+
+impl JsonSchema for ConstUnit {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        "ConstUnit".into()
+    }
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({ "type": "null" })
+    }
+}
+
+impl JsonSchema for ConstEmptyStr {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        "ConstEmptyStr".into()
+    }
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        const_schema(ConstEmptyStr)
+    }
+}
+
+impl JsonSchema for ConstEmptySeq {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        "ConstEmptySeq".into()
+    }
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        const_schema(ConstEmptySeq)
+    }
+}
+
+impl JsonSchema for ConstNanF64 {
+    fn inline_schema() -> bool {
+        true
+    }
+    fn schema_name() -> Cow<'static, str> {
+        "ConstNanF64".into()
+    }
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        // JSON can't represent NaN
+        json_schema!(false)
+    }
+}
+
+macro_rules! impl_json_schema_float {
+    ($($struct:ident<$bits:ty>),* $(,)?) => {
+        $(
+            impl<const BITS: $bits> JsonSchema for $struct<BITS> {
+                fn inline_schema() -> bool {
+                    true
+                }
+                fn schema_name() -> Cow<'static, str> {
+                    format!(concat!(stringify!($struct), "<{}>"), $struct::<BITS>).into()
+                }
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    if $struct::<BITS>::value().is_finite() {
+                        const_schema($struct::<BITS>)
+                    } else {
+                        // NaN never deserializes, and JSON can't represent infinities
+                        json_schema!(false)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_float!(ConstF32<u32>, ConstF64<u64>, ConstInfinityF64<bool>);
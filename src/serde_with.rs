@@ -0,0 +1,170 @@
+//! [`SerializeAs`] and [`DeserializeAs`] implementations, enabled by the `serde_with` feature.
+//!
+//! These let a constant validate a field of the underlying type, with
+//! `#[serde_as(as = "ConstI64<2>")]`. Deserialization fails unless the value is the constant, and
+//! serialization always writes the constant, whatever the field holds.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use serde_constant::ConstI64;
+//! use serde_json::json;
+//! use serde_with::serde_as;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Header {
+//!     #[serde_as(as = "ConstI64<2>")]
+//!     version: i64,
+//! }
+//!
+//! let header: Header = serde_json::from_value(json!({ "version": 2 })).unwrap();
+//! assert_eq!(header, Header { version: 2 });
+//! assert!(serde_json::from_value::<Header>(json!({ "version": 3 })).is_err());
+//! assert_eq!(
+//!     serde_json::to_value(Header { version: 5 }).unwrap(),
+//!     json!({ "version": 2 }),
+//! );
+//! ```
+//!
+//! [`ConstEmptyStr`] and [`ConstEmptySeq`] work with any field that has an empty [`Default`],
+//! like a `String` or a `Vec`.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use serde_constant::{ConstEmptySeq, ConstEmptyStr};
+//! use serde_json::json;
+//! use serde_with::serde_as;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Reserved {
+//!     #[serde_as(as = "ConstEmptyStr")]
+//!     note: String,
+//!     #[serde_as(as = "ConstEmptySeq")]
+//!     extensions: Vec<u32>,
+//! }
+//!
+//! let reserved: Reserved = serde_json::from_value(json!({ "note": "", "extensions": [] })).unwrap();
+//! assert_eq!(reserved, Reserved { note: String::new(), extensions: Vec::new() });
+//! assert!(serde_json::from_value::<Reserved>(json!({ "note": "", "extensions": [1] })).is_err());
+//! ```
+use crate::*;
+use ::serde_with::{DeserializeAs, SerializeAs};
+
+macro_rules! impl_serde_as {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> SerializeAs<<$struct<V> as ConstValue>::Repr> for $struct<V> {
+                fn serialize_as<S>(
+                    _: &<$struct<V> as ConstValue>::Repr,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    $struct::<V>.serialize(serializer)
+                }
+            }
+
+            impl<'de, const V: $type> DeserializeAs<'de, <$struct<V> as ConstValue>::Repr>
+                for $struct<V>
+            {
+                fn deserialize_as<D>(
+                    deserializer: D,
+                ) -> Result<<$struct<V> as ConstValue>::Repr, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    $struct::<V>::deserialize(deserializer).map(|_| <$struct<V> as ConstValue>::VALUE)
+                }
+            }
+        )*
+    };
+}
+
+with_const_types!(impl_serde_as);
+
+impl SerializeAs<()> for ConstUnit {
+    fn serialize_as<S>(_: &(), serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ConstUnit.serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, ()> for ConstUnit {
+    fn deserialize_as<D>(deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ConstUnit::deserialize(deserializer).map(|_| ())
+    }
+}
+
+impl SerializeAs<f64> for ConstNanF64 {
+    fn serialize_as<S>(_: &f64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ConstNanF64.serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, f64> for ConstNanF64 {
+    fn deserialize_as<D>(deserializer: D) -> Result<f64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ConstNanF64::deserialize(deserializer).map(f64::from)
+    }
+}
+
+// a field that's always empty can be a `String`, a `Vec`, or anything else with an empty default
+macro_rules! impl_serde_as_empty {
+    ($($struct:ident),* $(,)?) => {
+        $(
+            impl<T: ?Sized> SerializeAs<T> for $struct {
+                fn serialize_as<S>(_: &T, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    $struct.serialize(serializer)
+                }
+            }
+
+            impl<'de, T: Default> DeserializeAs<'de, T> for $struct {
+                fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    $struct::deserialize(deserializer).map(|_| T::default())
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_as_empty!(ConstEmptyStr, ConstEmptySeq);
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> SerializeAs<i64> for ConstLabeled<V, NAME> {
+    fn serialize_as<S>(_: &i64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ConstLabeled::<V, NAME>.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'de, const V: i64, const NAME: &'static str> DeserializeAs<'de, i64>
+    for ConstLabeled<V, NAME>
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<i64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ConstLabeled::<V, NAME>::deserialize(deserializer).map(|_| V)
+    }
+}
@@ -0,0 +1,138 @@
+//! Helpers for testing how constants interact with serde, enabled by the `test-util` feature.
+//!
+//! These wrap [`serde_test`], so each check is a single call instead of spelling out a token
+//! stream and an instance of the constant. Constants are tested in human-readable mode.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde_constant::testing::{assert_const_rejects, assert_const_roundtrip};
+//! use serde_constant::*;
+//! use serde_test::Token;
+//!
+//! assert_const_roundtrip::<ConstBool<true>>(Token::Bool(true));
+//! assert_const_roundtrip::<ConstI8<-8>>(Token::I8(-8));
+//! assert_const_roundtrip::<ConstI16<-16>>(Token::I16(-16));
+//! assert_const_roundtrip::<ConstI32<-32>>(Token::I32(-32));
+//! assert_const_roundtrip::<ConstI64<-64>>(Token::I64(-64));
+//! assert_const_roundtrip::<ConstIsize<-64>>(Token::I64(-64));
+//! assert_const_roundtrip::<ConstU8<8>>(Token::U8(8));
+//! assert_const_roundtrip::<ConstU16<16>>(Token::U16(16));
+//! assert_const_roundtrip::<ConstU32<32>>(Token::U32(32));
+//! assert_const_roundtrip::<ConstU64<64>>(Token::U64(64));
+//! assert_const_roundtrip::<ConstUsize<64>>(Token::U64(64));
+//! assert_const_roundtrip::<ConstNonZeroU32<7>>(Token::U32(7));
+//! assert_const_roundtrip::<ConstChar<'x'>>(Token::Char('x'));
+//! assert_const_roundtrip::<ConstCharCi<'x'>>(Token::Char('x'));
+//! assert_const_roundtrip::<ConstUnit>(Token::Unit);
+//! assert_const_roundtrip::<const_f32!(1.5)>(Token::F32(1.5));
+//! assert_const_roundtrip::<const_f64!(1.5)>(Token::F64(1.5));
+//! assert_const_roundtrip::<ConstInfinityF64<true>>(Token::F64(f64::INFINITY));
+//! assert_const_roundtrip::<ConstScalarU32<0x1F980>>(Token::U32(0x1F980));
+//! assert_const_roundtrip::<ConstSafeU64<{ 1 << 53 }>>(Token::U64(1 << 53));
+//! assert_const_roundtrip::<ConstPrintableChar<'x'>>(Token::Char('x'));
+//!
+//! assert_const_rejects::<ConstBool<true>>(
+//!     Token::Bool(false),
+//!     "invalid value: boolean `false`, expected the constant bool true",
+//! );
+//! // the wording around a rejected integer depends on the `alloc` feature, so match the part
+//! // that's always there
+//! assert_const_rejects::<ConstI64<2>>(Token::I64(3), "expected the constant i64 2");
+//! assert_const_rejects::<ConstU8<5>>(Token::U8(6), "expected the constant u8 5");
+//! assert_const_rejects::<ConstScalarU32<0x1F980>>(Token::U32(0x41), "expected the constant u32");
+//! assert_const_rejects::<ConstSafeU64<7>>(Token::U64(8), "expected the constant u64 7");
+//! assert_const_rejects::<ConstChar<'x'>>(
+//!     Token::Char('y'),
+//!     "invalid value: character `y`, expected the constant char 'x'",
+//! );
+//! assert_const_rejects::<ConstPrintableChar<'x'>>(Token::Char('y'), "character `y`");
+//! assert_const_rejects::<ConstUnit>(
+//!     Token::Bool(false),
+//!     "invalid type: boolean `false`, expected the constant null",
+//! );
+//! assert_const_rejects::<const_f64!(1.5)>(
+//!     Token::F64(2.5),
+//!     "invalid value: floating point `2.5`, expected the constant f64 1.5",
+//! );
+//! assert_const_rejects::<ConstInfinityF64<true>>(Token::F64(f64::NEG_INFINITY), "expected");
+//! ```
+//!
+//! `serde_test` has no 128-bit tokens, so 128-bit constants can't be round-tripped, but they can
+//! still be checked against smaller values:
+//!
+//! ```
+//! use serde_constant::testing::assert_const_rejects;
+//! use serde_constant::{ConstI128, ConstU128};
+//! use serde_test::Token;
+//!
+//! assert_const_rejects::<ConstI128<{ i128::MIN }>>(Token::I64(-1), "expected the constant i128");
+//! assert_const_rejects::<ConstU128<{ u128::MAX }>>(Token::U64(1), "expected the constant u128");
+//! ```
+use alloc::string::{String, ToString};
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+/// Asserts that `T` serializes to `token`, and deserializes from it.
+pub fn assert_const_roundtrip<T>(token: Token)
+where
+    T: Default + Serialize + for<'de> Deserialize<'de> + PartialEq + core::fmt::Debug,
+{
+    assert_tokens(&T::default().readable(), &[token]);
+}
+
+/// Asserts that deserializing `T` from `token` fails with an error message containing `error`.
+pub fn assert_const_rejects<T>(token: Token, error: &str)
+where
+    T: for<'de> Deserialize<'de>,
+{
+    assert_de_tokens(&Rejection::<T>::Containing(error).readable(), &[token]);
+}
+
+/// The outcome of deserializing a `T`, which `serde_test` compares against the expected fragment.
+///
+/// `serde_test` only checks errors for an exact match, so the error is deserialized as a value
+/// instead, and equality is containment.
+enum Rejection<'a, T> {
+    /// What the test expects, a failure with a message containing this.
+    Containing(&'a str),
+    /// Deserialization failed with this message.
+    Failed(String),
+    /// Deserialization succeeded.
+    Accepted(PhantomData<T>),
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Rejection<'_, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match T::deserialize(deserializer) {
+            Ok(_) => Rejection::Accepted(PhantomData),
+            Err(err) => Rejection::Failed(err.to_string()),
+        })
+    }
+}
+
+impl<T> PartialEq for Rejection<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Rejection::Containing(fragment), Rejection::Failed(message))
+            | (Rejection::Failed(message), Rejection::Containing(fragment)) => {
+                message.contains(fragment)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for Rejection<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Rejection::Containing(fragment) => write!(f, "an error containing {fragment:?}"),
+            Rejection::Failed(message) => write!(f, "the error {message:?}"),
+            Rejection::Accepted(_) => f.write_str("a successful deserialization"),
+        }
+    }
+}
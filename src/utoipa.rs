@@ -0,0 +1,224 @@
+//! [`ToSchema`] implementations, enabled by the `utoipa` feature.
+//!
+//! OpenAPI 3.0 has no `const` keyword, so every constant produces a schema with a single-element
+//! `enum`, using the same JSON value that the constant serializes to.
+//!
+//! ```
+//! use serde_constant::{const_f64, ConstBool, ConstChar, ConstCharCi, ConstI64, ConstUnit};
+//! use serde_json::json;
+//! use utoipa::{PartialSchema, ToSchema};
+//!
+//! let schema = serde_json::to_value(ConstI64::<2>::schema()).unwrap();
+//! assert_eq!(schema, json!({ "type": "integer", "enum": [2] }));
+//! let schema = serde_json::to_value(ConstBool::<true>::schema()).unwrap();
+//! assert_eq!(schema, json!({ "type": "boolean", "enum": [true] }));
+//! let schema = serde_json::to_value(ConstChar::<'c'>::schema()).unwrap();
+//! assert_eq!(schema, json!({ "type": "string", "enum": ["c"] }));
+//! let schema = serde_json::to_value(<const_f64!(1.5)>::schema()).unwrap();
+//! assert_eq!(schema, json!({ "type": "number", "enum": [1.5] }));
+//! let schema = serde_json::to_value(ConstUnit::schema()).unwrap();
+//! assert_eq!(schema, json!({ "type": "null", "enum": [null] }));
+//! assert_eq!(ConstI64::<2>::name(), "ConstI64<2>");
+//!
+//! // case-insensitive characters list every spelling the deserializer accepts
+//! let schema = serde_json::to_value(ConstCharCi::<'Σ'>::schema()).unwrap();
+//! assert_eq!(schema, json!({ "type": "string", "enum": ["Σ", "σ"] }));
+//! ```
+use crate::*;
+use ::utoipa::{
+    openapi::{schema::Type, ObjectBuilder, RefOr, Schema},
+    PartialSchema, ToSchema,
+};
+use alloc::{borrow::Cow, format};
+use serde_json::Value;
+
+/// Builds a schema matching only the serialized form of `value`.
+fn const_schema<T: Serialize>(value: T) -> RefOr<Schema> {
+    let value = match serde_json::to_value(value) {
+        Ok(value) => value,
+        // serde_json can't represent integers outside the 64-bit range
+        Err(_) => return ObjectBuilder::new().schema_type(Type::Integer).into(),
+    };
+    let schema_type = match &value {
+        Value::Null => Type::Null,
+        Value::Bool(_) => Type::Boolean,
+        Value::Number(n) if n.is_f64() => Type::Number,
+        Value::Number(_) => Type::Integer,
+        Value::String(_) => Type::String,
+        Value::Array(_) => Type::Array,
+        Value::Object(_) => Type::Object,
+    };
+    ObjectBuilder::new()
+        .schema_type(schema_type)
+        .enum_values(Some([value]))
+        .into()
+}
+
+macro_rules! impl_to_schema {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> PartialSchema for $struct<V> {
+                fn schema() -> RefOr<Schema> {
+                    const_schema($struct::<V>)
+                }
+            }
+            impl<const V: $type> ToSchema for $struct<V> {
+                fn name() -> Cow<'static, str> {
+                    format!(concat!(stringify!($struct), "<{}>"), $struct::<V>).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_schema!(
+    ConstBool<bool>,
+    ConstI8<i8>,
+    ConstI16<i16>,
+    ConstI32<i32>,
+    ConstI64<i64>,
+    ConstI128<i128>,
+    ConstIsize<isize>,
+    ConstU8<u8>,
+    ConstU16<u16>,
+    ConstU32<u32>,
+    ConstU64<u64>,
+    ConstU128<u128>,
+    ConstUsize<usize>,
+    ConstNonZeroI8<i8>,
+    ConstNonZeroI16<i16>,
+    ConstNonZeroI32<i32>,
+    ConstNonZeroI64<i64>,
+    ConstNonZeroI128<i128>,
+    ConstNonZeroIsize<isize>,
+    ConstNonZeroU8<u8>,
+    ConstNonZeroU16<u16>,
+    ConstNonZeroU32<u32>,
+    ConstNonZeroU64<u64>,
+    ConstNonZeroU128<u128>,
+    ConstNonZeroUsize<usize>,
+    ConstChar<char>,
+    ConstPrintableChar<char>,
+    ConstScalarU32<u32>,
+    ConstSafeU64<u64>,
+);
+
+#[cfg(feature = "nightly")]
+impl_to_schema!(
+    ConstStr<&'static str>,
+    ConstUnitVariant<&'static str>,
+    ConstBytes<&'static [u8]>,
+);
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> PartialSchema for ConstLabeled<V, NAME> {
+    fn schema() -> RefOr<Schema> {
+        const_schema(ConstLabeled::<V, NAME>)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> ToSchema for ConstLabeled<V, NAME> {
+    fn name() -> Cow<'static, str> {
+        format!("ConstLabeled<{V}, {NAME:?}>").into()
+    }
+}
+
+impl<const V: char> PartialSchema for ConstCharCi<V> {
+    fn schema() -> RefOr<Schema> {
+        // OpenAPI has no case-insensitive matching, so list the spellings
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .enum_values(Some(
+                ConstCharCi::<V>::spellings()
+                    .into_iter()
+                    .map(|ch| format!("{ch}")),
+            ))
+            .into()
+    }
+}
+
+impl<const V: char> ToSchema for ConstCharCi<V> {
+    fn name() -> Cow<'static, str> {
+        format!("ConstCharCi<{}>", ConstCharCi::<V>).into()
+    }
+}
+
+impl PartialSchema for ConstUnit {
+    fn schema() -> RefOr<Schema> {
+        const_schema(ConstUnit)
+    }
+}
+
+impl ToSchema for ConstUnit {
+    fn name() -> Cow<'static, str> {
+        "ConstUnit".into()
+    }
+}
+
+impl PartialSchema for ConstEmptyStr {
+    fn schema() -> RefOr<Schema> {
+        const_schema(ConstEmptyStr)
+    }
+}
+
+impl ToSchema for ConstEmptyStr {
+    fn name() -> Cow<'static, str> {
+        "ConstEmptyStr".into()
+    }
+}
+
+impl PartialSchema for ConstEmptySeq {
+    fn schema() -> RefOr<Schema> {
+        const_schema(ConstEmptySeq)
+    }
+}
+
+impl ToSchema for ConstEmptySeq {
+    fn name() -> Cow<'static, str> {
+        "ConstEmptySeq".into()
+    }
+}
+
+impl PartialSchema for ConstNanF64 {
+    fn schema() -> RefOr<Schema> {
+        // JSON can't represent NaN, so allow no values
+        ObjectBuilder::new()
+            .schema_type(Type::Number)
+            .enum_values(Some([] as [Value; 0]))
+            .into()
+    }
+}
+
+impl ToSchema for ConstNanF64 {
+    fn name() -> Cow<'static, str> {
+        "ConstNanF64".into()
+    }
+}
+
+macro_rules! impl_to_schema_float {
+    ($($struct:ident<$bits:ty>),* $(,)?) => {
+        $(
+            impl<const BITS: $bits> PartialSchema for $struct<BITS> {
+                fn schema() -> RefOr<Schema> {
+                    if $struct::<BITS>::value().is_finite() {
+                        const_schema($struct::<BITS>)
+                    } else {
+                        // NaN never deserializes, and JSON can't represent infinities, so allow no values
+                        ObjectBuilder::new()
+                            .schema_type(Type::Number)
+                            .enum_values(Some([] as [Value; 0]))
+                            .into()
+                    }
+                }
+            }
+            impl<const BITS: $bits> ToSchema for $struct<BITS> {
+                fn name() -> Cow<'static, str> {
+                    format!(concat!(stringify!($struct), "<{}>"), $struct::<BITS>).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_schema_float!(ConstF32<u32>, ConstF64<u64>, ConstInfinityF64<bool>);
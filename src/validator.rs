@@ -0,0 +1,175 @@
+//! [`Validate`] implementations and [`ConstField`], enabled by the `validator` feature.
+//!
+//! A constant is always valid, since having one means having its value. [`ConstField`] holds a
+//! value that is only checked against the constant when it's validated, with
+//! [`ConstField::validate_const`] as a `custom` validator, so that a mismatch is reported
+//! alongside the struct's other field errors.
+//!
+//! ```
+//! use serde_constant::{ConstBool, ConstField, ConstI64};
+//! use validator::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Request {
+//!     #[validate(custom(function = "ConstField::validate_const"))]
+//!     version: ConstField<ConstI64<2>>,
+//!     #[validate(nested)]
+//!     signed: ConstBool<true>,
+//!     #[validate(length(min = 1))]
+//!     name: String,
+//! }
+//!
+//! let request = Request {
+//!     version: ConstField::new(2),
+//!     signed: ConstBool,
+//!     name: "Ferris".into(),
+//! };
+//! assert!(request.validate().is_ok());
+//!
+//! let request = Request {
+//!     version: ConstField::new(3),
+//!     signed: ConstBool,
+//!     name: String::new(),
+//! };
+//! let errors = request.validate().unwrap_err();
+//! let fields = errors.field_errors();
+//! assert_eq!(fields["version"][0].code, "const");
+//! assert_eq!(fields["version"][0].params["found"], 3);
+//! assert_eq!(fields["version"][0].to_string(), "expected 2, found 3");
+//! assert_eq!(fields["name"][0].code, "length");
+//! ```
+use crate::*;
+use ::validator::{Validate, ValidationError, ValidationErrors};
+use alloc::{borrow::Cow, string::ToString};
+
+macro_rules! impl_validate {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> Validate for $struct<V> {
+                fn validate(&self) -> Result<(), ValidationErrors> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+with_const_types!(impl_validate);
+
+impl Validate for ConstUnit {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
+impl Validate for ConstNanF64 {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
+impl Validate for ConstEmptyStr {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
+impl Validate for ConstEmptySeq {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> Validate for ConstLabeled<V, NAME> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
+/// A value of the constant `C`'s underlying type, which is checked against the constant when it's
+/// validated.
+///
+/// Validate it with `#[validate(custom(function = "ConstField::validate_const"))]`. A mismatch is
+/// reported as an error on the field, with the code `"const"`, the [`ConstMismatch`] as the
+/// message, and its `expected` and `found` values as params.
+///
+/// It serializes and deserializes as the bare value, without checking it, so that a struct can be
+/// deserialized first and validated afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use serde_constant::{ConstChar, ConstField};
+///
+/// assert!(ConstField::<ConstChar<'x'>>::new('x').validate_const().is_ok());
+/// let error = ConstField::<ConstChar<'x'>>::new('y').validate_const().unwrap_err();
+/// assert_eq!(error.code, "const");
+/// assert_eq!(ConstField::from(ConstChar::<'x'>).0, 'x');
+///
+/// let unchecked: ConstField<ConstChar<'x'>> = serde_json::from_str("\"y\"").unwrap();
+/// assert!(unchecked.validate_const().is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConstField<C: ConstValue>(pub C::Repr);
+
+impl<C: ConstValue> ConstField<C> {
+    /// Wraps a value, without checking it against the constant.
+    pub const fn new(value: C::Repr) -> Self {
+        ConstField(value)
+    }
+}
+
+impl<C> ConstField<C>
+where
+    C: ConstValue + TryFrom<C::Repr, Error = ConstMismatch<C::Repr>>,
+    C::Repr: Clone + fmt::Display + Serialize,
+{
+    /// Checks the value against the constant, for use as a `custom` validator.
+    pub fn validate_const(&self) -> Result<(), ValidationError> {
+        let Err(mismatch) = C::try_from(self.0.clone()) else {
+            return Ok(());
+        };
+        let mut error =
+            ValidationError::new("const").with_message(Cow::Owned(mismatch.to_string()));
+        error.add_param(Cow::Borrowed("expected"), &mismatch.expected);
+        error.add_param(Cow::Borrowed("found"), &mismatch.found);
+        Err(error)
+    }
+}
+
+impl<C: ConstValue> Default for ConstField<C> {
+    fn default() -> Self {
+        ConstField(C::VALUE)
+    }
+}
+
+impl<C: ConstValue> From<C> for ConstField<C> {
+    fn from(_: C) -> Self {
+        ConstField(C::VALUE)
+    }
+}
+
+impl<C: ConstValue> Serialize for ConstField<C>
+where
+    C::Repr: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, C: ConstValue> Deserialize<'de> for ConstField<C>
+where
+    C::Repr: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        C::Repr::deserialize(deserializer).map(ConstField)
+    }
+}
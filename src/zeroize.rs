@@ -0,0 +1,68 @@
+//! [`Zeroize`] and [`ZeroizeOnDrop`] implementations, enabled by the `zeroize` feature.
+//!
+//! Constants hold no data, so there's nothing to clear. These exist so that structs containing
+//! constants can derive the traits.
+//!
+//! ```
+//! use serde_constant::ConstU8;
+//! use zeroize::{Zeroize, ZeroizeOnDrop};
+//!
+//! #[derive(Zeroize, ZeroizeOnDrop)]
+//! struct Secret {
+//!     version: ConstU8<1>,
+//!     key: [u8; 32],
+//! }
+//!
+//! let mut secret = Secret { version: ConstU8, key: [7; 32] };
+//! secret.zeroize();
+//! assert_eq!(secret.key, [0; 32]);
+//! assert_eq!(secret.version, 1);
+//! ```
+use crate::*;
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+macro_rules! impl_zeroize {
+    ($($struct:ident<$type:ty>),* $(,)?) => {
+        $(
+            impl<const V: $type> Zeroize for $struct<V> {
+                fn zeroize(&mut self) {}
+            }
+
+            impl<const V: $type> ZeroizeOnDrop for $struct<V> {}
+        )*
+    };
+}
+
+with_const_types!(impl_zeroize);
+
+impl Zeroize for ConstUnit {
+    fn zeroize(&mut self) {}
+}
+
+impl ZeroizeOnDrop for ConstUnit {}
+
+impl Zeroize for ConstNanF64 {
+    fn zeroize(&mut self) {}
+}
+
+impl ZeroizeOnDrop for ConstNanF64 {}
+
+impl Zeroize for ConstEmptyStr {
+    fn zeroize(&mut self) {}
+}
+
+impl ZeroizeOnDrop for ConstEmptyStr {}
+
+impl Zeroize for ConstEmptySeq {
+    fn zeroize(&mut self) {}
+}
+
+impl ZeroizeOnDrop for ConstEmptySeq {}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> Zeroize for ConstLabeled<V, NAME> {
+    fn zeroize(&mut self) {}
+}
+
+#[cfg(feature = "nightly")]
+impl<const V: i64, const NAME: &'static str> ZeroizeOnDrop for ConstLabeled<V, NAME> {}
@@ -0,0 +1,13 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/fail/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+    #[cfg(feature = "nightly")]
+    t.compile_fail("tests/ui/nightly/*.rs");
+    #[cfg(feature = "derive")]
+    {
+        t.compile_fail("tests/ui/derive/fail/*.rs");
+        t.pass("tests/ui/derive/pass/*.rs");
+    }
+}
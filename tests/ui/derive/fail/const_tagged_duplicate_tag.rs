@@ -0,0 +1,10 @@
+use serde_constant::ConstTagged;
+
+#[derive(ConstTagged)]
+enum Message {
+    #[const_tag(1)]
+    #[const_tag(2)]
+    Ping { id: u64 },
+}
+
+fn main() {}
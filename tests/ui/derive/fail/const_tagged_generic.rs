@@ -0,0 +1,9 @@
+use serde_constant::ConstTagged;
+
+#[derive(ConstTagged)]
+enum Message<T> {
+    #[const_tag(1)]
+    Ping { id: T },
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use serde_constant::ConstTagged;
+
+#[derive(ConstTagged)]
+enum Message {
+    #[const_tag(1)]
+    Ping { id: u64 },
+    Pong { id: u64 },
+    Close,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use serde_constant::ConstTagged;
+
+#[derive(ConstTagged)]
+enum Message {
+    #[const_tag(1)]
+    Ping {},
+    #[const_tag(2)]
+    Pong { seq: u8 },
+    #[const_tag(1)]
+    Close { code: u8 },
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use serde_constant::ConstTagged;
+
+#[derive(ConstTagged)]
+struct Message {
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_constant::ConstTagged;
+
+#[derive(ConstTagged)]
+enum Message {
+    #[const_tag(1)]
+    Ping { tag: u64 },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_constant::ConstTagged;
+
+#[derive(ConstTagged)]
+enum Message {
+    #[const_tag(1)]
+    Ping(u64),
+}
+
+fn main() {}
@@ -0,0 +1,91 @@
+#![deny(warnings)]
+
+use serde::{Deserialize, Serialize};
+use serde_constant::{ConstI64, ConstTagged};
+use serde_json::{json, Value};
+
+// the derive, and the enum it should behave like
+#[derive(ConstTagged, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+enum Event {
+    #[const_tag(1)]
+    Start {
+        #[serde(rename = "ID")]
+        id: u64,
+    },
+    #[const_tag(1 + 1)]
+    Stop {
+        id: u64,
+        #[serde(default, skip_serializing_if = "is_none")]
+        reason: Option<String>,
+    },
+    #[const_tag(-1)]
+    Reset,
+}
+
+mod by_hand {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    #[serde(untagged, deny_unknown_fields)]
+    pub enum Event {
+        Start {
+            tag: ConstI64<1>,
+            #[serde(rename = "ID")]
+            id: u64,
+        },
+        Stop {
+            tag: ConstI64<2>,
+            id: u64,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            reason: Option<String>,
+        },
+        Reset {
+            tag: ConstI64<-1>,
+        },
+    }
+}
+
+// serialized fields are references
+fn is_none(value: &&Option<String>) -> bool {
+    value.is_none()
+}
+
+#[derive(ConstTagged, Debug, PartialEq)]
+enum Unit {
+    #[const_tag(0)]
+    Off,
+    #[const_tag(1)]
+    On,
+}
+
+fn main() {
+    let cases = [
+        json!({ "tag": 1, "ID": 5 }),
+        json!({ "tag": 2, "id": 5 }),
+        json!({ "tag": 2, "id": 5, "reason": "done" }),
+        json!({ "tag": -1 }),
+    ];
+    for case in cases {
+        let derived = serde_json::from_value::<Event>(case.clone()).unwrap();
+        let by_hand = serde_json::from_value::<by_hand::Event>(case.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&derived).unwrap(), case);
+        assert_eq!(serde_json::to_value(&by_hand).unwrap(), case);
+    }
+
+    let failures = [
+        json!({ "tag": 3, "id": 5 }),
+        json!({ "tag": 1, "id": 5 }),
+        json!({ "tag": -1, "extra": true }),
+        json!([1, 5]),
+    ];
+    for case in failures {
+        let derived = serde_json::from_value::<Event>(case.clone()).unwrap_err();
+        let by_hand = serde_json::from_value::<by_hand::Event>(case).unwrap_err();
+        assert_eq!(derived.to_string(), by_hand.to_string());
+    }
+
+    assert_eq!(serde_json::to_value(Unit::On).unwrap(), json!({ "tag": 1 }));
+    assert_eq!(serde_json::from_value::<Unit>(json!({ "tag": 0 })).unwrap(), Unit::Off);
+    assert!(serde_json::from_value::<Unit>(Value::from(0)).is_err());
+}
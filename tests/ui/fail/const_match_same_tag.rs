@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use serde_constant::const_match;
+
+const_match! {
+    #[derive(Deserialize)]
+    enum Msg {
+        1 => Ping {},
+        2 => Pong { seq: u32 },
+        1 => Close { code: u16 },
+    }
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use serde_constant::ConstNonZeroI64;
+
+fn main() {
+    println!("{:?}", ConstNonZeroI64::<0>);
+}
@@ -0,0 +1,5 @@
+use serde_constant::ConstNonZeroU32;
+
+fn main() {
+    let _ = serde_json::from_str::<ConstNonZeroU32<0>>("0");
+}
@@ -0,0 +1,5 @@
+use serde_constant::ConstNonZeroU32;
+
+fn main() {
+    println!("{}", ConstNonZeroU32::<0>);
+}
@@ -0,0 +1,5 @@
+use serde_constant::ConstPrintableChar;
+
+fn main() {
+    let _ = serde_json::to_value(ConstPrintableChar::<'\n'>);
+}
@@ -0,0 +1,5 @@
+use serde_constant::ConstPrintableChar;
+
+fn main() {
+    ConstPrintableChar::<'\u{7f}'>.get();
+}
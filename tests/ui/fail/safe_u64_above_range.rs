@@ -0,0 +1,5 @@
+use serde_constant::ConstSafeU64;
+
+fn main() {
+    let _ = serde_json::to_value(ConstSafeU64::<{ (1 << 53) + 1 }>);
+}
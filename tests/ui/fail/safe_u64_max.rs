@@ -0,0 +1,5 @@
+use serde_constant::ConstSafeU64;
+
+fn main() {
+    ConstSafeU64::<{ u64::MAX }>.get();
+}
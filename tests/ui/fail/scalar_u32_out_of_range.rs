@@ -0,0 +1,5 @@
+use serde_constant::ConstScalarU32;
+
+fn main() {
+    ConstScalarU32::<0x110000>.get();
+}
@@ -0,0 +1,5 @@
+use serde_constant::ConstScalarU32;
+
+fn main() {
+    let _ = serde_json::to_value(ConstScalarU32::<0xD800>);
+}
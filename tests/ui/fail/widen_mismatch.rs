@@ -0,0 +1,5 @@
+use serde_constant::{ConstU16, ConstU8};
+
+fn main() {
+    let _: ConstU16<6> = ConstU8::<5>.widen();
+}
@@ -0,0 +1,5 @@
+use serde_constant::ConstI8;
+
+fn main() {
+    let _ = ConstI8::<-1>.widen::<255>();
+}
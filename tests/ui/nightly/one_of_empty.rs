@@ -0,0 +1,5 @@
+use serde_constant::ConstOneOfI64;
+
+fn main() {
+    let _ = ConstOneOfI64::<{ &[] }>::default();
+}
@@ -0,0 +1,81 @@
+#![deny(warnings)]
+
+use serde::{Deserialize, Serialize};
+use serde_constant::{const_match, ConstI64, ConstU8};
+use serde_json::json;
+
+const_match! {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    pub enum Msg {
+        1 => Ping {},
+        2 => Pong { seq: u32 },
+        -1 => Error {
+            #[serde(default)]
+            reason: Option<String>,
+        },
+    }
+}
+
+const_match! {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Op(op: ConstU8) {
+        0 => Nop {},
+        1 => Add { value: u8 },
+    }
+}
+
+// what `Msg` expands to, apart from its methods
+mod by_hand {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    #[serde(untagged)]
+    pub enum Msg {
+        Ping {
+            tag: ConstI64<1>,
+        },
+        Pong {
+            tag: ConstI64<2>,
+            seq: u32,
+        },
+        Error {
+            tag: ConstI64<-1>,
+            #[serde(default)]
+            reason: Option<String>,
+        },
+    }
+}
+
+fn main() {
+    let cases = [
+        json!({ "tag": 1 }),
+        json!({ "tag": 2, "seq": 7 }),
+        json!({ "tag": -1, "reason": null }),
+    ];
+    for case in cases {
+        let expanded = serde_json::from_value::<Msg>(case.clone()).unwrap();
+        let by_hand = serde_json::from_value::<by_hand::Msg>(case.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&expanded).unwrap(), case);
+        assert_eq!(serde_json::to_value(&by_hand).unwrap(), case);
+        assert_eq!(Msg::variant_name(expanded.tag()), Some(variant(&by_hand)));
+    }
+    let error = serde_json::from_value::<Msg>(json!({ "tag": -1 })).unwrap();
+    assert_eq!(error, Msg::Error { tag: ConstI64, reason: None });
+    assert!(serde_json::from_value::<Msg>(json!({ "tag": 3 })).is_err());
+    assert!(serde_json::from_value::<Msg>(json!({ "tag": 2 })).is_err());
+    assert_eq!(Msg::TAGS, [1, 2, -1]);
+
+    let add = serde_json::from_value::<Op>(json!({ "op": 1, "value": 3 })).unwrap();
+    assert_eq!(add, Op::Add { op: ConstU8, value: 3 });
+    assert_eq!(add.tag(), 1u8);
+    assert_eq!(Op::variant_name(0), Some("Nop"));
+    assert!(serde_json::from_value::<Op>(json!({ "tag": 1, "value": 3 })).is_err());
+}
+
+fn variant(msg: &by_hand::Msg) -> &'static str {
+    match msg {
+        by_hand::Msg::Ping { .. } => "Ping",
+        by_hand::Msg::Pong { .. } => "Pong",
+        by_hand::Msg::Error { .. } => "Error",
+    }
+}
@@ -0,0 +1,6 @@
+use serde_constant::ConstPrintableChar;
+
+fn main() {
+    assert_eq!(serde_json::to_value(ConstPrintableChar::<'#'>).unwrap(), "#");
+    assert_eq!(ConstPrintableChar::<'#'>.get(), '#');
+}
@@ -0,0 +1,7 @@
+use serde_constant::ConstSafeU64;
+
+fn main() {
+    let json = serde_json::to_value(ConstSafeU64::<{ 1 << 53 }>).unwrap();
+    assert_eq!(json, 9007199254740992u64);
+    assert_eq!(ConstSafeU64::<7>.get(), 7);
+}
@@ -0,0 +1,6 @@
+use serde_constant::ConstScalarU32;
+
+fn main() {
+    assert_eq!(serde_json::to_value(ConstScalarU32::<0xD7FF>).unwrap(), 0xD7FF);
+    assert_eq!(ConstScalarU32::<0x10FFFF>::CHAR, '\u{10FFFF}');
+}
@@ -0,0 +1,9 @@
+use serde_constant::{ConstI16, ConstI32, ConstI8, ConstU16, ConstU8};
+
+fn main() {
+    let wide: ConstU16<5> = ConstU8::<5>.widen();
+    assert_eq!(wide, 5);
+    let wider: ConstI32<-1> = ConstI8::<-1>.widen::<-1>().widen();
+    assert_eq!(wider, -1);
+    assert_eq!(ConstI8::<-128>.widen::<-128>(), ConstI16::<-128>);
+}